@@ -0,0 +1,31 @@
+//! This crate exists to verify that tryiter builds for targets without the
+//! standard library. CI builds it for a bare metal target:
+//!
+//! ```sh
+//! cargo build --manifest-path ci/no-std/Cargo.toml --target thumbv7em-none-eabihf
+//! ```
+
+#![no_std]
+
+use tryiter::TryIteratorExt;
+
+pub fn sum_even(vals: &[Result<u32, u8>]) -> Result<u32, u8> {
+    let mut iter = vals
+        .iter()
+        .copied()
+        .map_ok(|x| Ok(x * 2))
+        .try_filter(|x| Ok(x % 4 == 0))
+        .try_peekable();
+
+    let mut sum = 0;
+    while let Some(x) = iter.try_next()? {
+        sum += x;
+    }
+    Ok(sum)
+}
+
+pub fn max_back(vals: &[Result<u32, u8>]) -> Result<Option<u32>, u8> {
+    let mut iter = vals.iter().copied().err_into::<u16>().try_peekable();
+    iter.try_peek_back().map_err(|e| e as u8)?;
+    iter.map_err(|e| e as u8).try_max()
+}
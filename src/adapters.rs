@@ -0,0 +1,876 @@
+use core::fmt;
+use core::iter::{Fuse, FusedIterator};
+use core::marker::PhantomData;
+
+use crate::TryIterator;
+
+/// An iterator that converts the error type of the underlying iterator using
+/// [`Into`].
+///
+/// This `struct` is created by the [`err_into`] method on [`TryIteratorExt`].
+/// See its documentation for more.
+///
+/// [`err_into`]: crate::TryIteratorExt::err_into
+/// [`TryIteratorExt`]: crate::TryIteratorExt
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct ErrInto<I, E> {
+    iter: I,
+    _marker: PhantomData<fn() -> E>,
+}
+
+impl<I, E> ErrInto<I, E> {
+    pub(crate) fn new(iter: I) -> Self {
+        Self {
+            iter,
+            _marker: PhantomData,
+        }
+    }
+}
+
+impl<I: Clone, E> Clone for ErrInto<I, E> {
+    fn clone(&self) -> Self {
+        Self::new(self.iter.clone())
+    }
+}
+
+impl<I: fmt::Debug, E> fmt::Debug for ErrInto<I, E> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ErrInto").field("iter", &self.iter).finish()
+    }
+}
+
+impl<I, E> Iterator for ErrInto<I, E>
+where
+    I: TryIterator,
+    I::Err: Into<E>,
+{
+    type Item = Result<I::Ok, E>;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        self.iter.next().map(|result| result.map_err(Into::into))
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+impl<I, E> DoubleEndedIterator for ErrInto<I, E>
+where
+    I: TryIterator + DoubleEndedIterator,
+    I::Err: Into<E>,
+{
+    #[inline]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.iter
+            .next_back()
+            .map(|result| result.map_err(Into::into))
+    }
+}
+
+impl<I, E> ExactSizeIterator for ErrInto<I, E>
+where
+    I: TryIterator + ExactSizeIterator,
+    I::Err: Into<E>,
+{
+}
+
+impl<I, E> FusedIterator for ErrInto<I, E>
+where
+    I: TryIterator + FusedIterator,
+    I::Err: Into<E>,
+{
+}
+
+/// An iterator that maps the success values of the underlying iterator with a
+/// fallible closure.
+///
+/// This `struct` is created by the [`map_ok`] method on [`TryIteratorExt`].
+/// See its documentation for more.
+///
+/// [`map_ok`]: crate::TryIteratorExt::map_ok
+/// [`TryIteratorExt`]: crate::TryIteratorExt
+#[derive(Clone)]
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct MapOk<I, F> {
+    iter: I,
+    f: F,
+}
+
+impl<I, F> MapOk<I, F> {
+    pub(crate) fn new(iter: I, f: F) -> Self {
+        Self { iter, f }
+    }
+}
+
+impl<I: fmt::Debug, F> fmt::Debug for MapOk<I, F> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("MapOk").field("iter", &self.iter).finish()
+    }
+}
+
+impl<I, F, T> Iterator for MapOk<I, F>
+where
+    I: TryIterator,
+    F: FnMut(I::Ok) -> Result<T, I::Err>,
+{
+    type Item = Result<T, I::Err>;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        self.iter.next().map(|result| result.and_then(&mut self.f))
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+impl<I, F, T> DoubleEndedIterator for MapOk<I, F>
+where
+    I: TryIterator + DoubleEndedIterator,
+    F: FnMut(I::Ok) -> Result<T, I::Err>,
+{
+    #[inline]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.iter
+            .next_back()
+            .map(|result| result.and_then(&mut self.f))
+    }
+}
+
+impl<I, F, T> ExactSizeIterator for MapOk<I, F>
+where
+    I: TryIterator + ExactSizeIterator,
+    F: FnMut(I::Ok) -> Result<T, I::Err>,
+{
+}
+
+impl<I, F, T> FusedIterator for MapOk<I, F>
+where
+    I: TryIterator + FusedIterator,
+    F: FnMut(I::Ok) -> Result<T, I::Err>,
+{
+}
+
+/// An iterator that maps the error values of the underlying iterator with a
+/// closure.
+///
+/// This `struct` is created by the [`map_err`] method on [`TryIteratorExt`].
+/// See its documentation for more.
+///
+/// [`map_err`]: crate::TryIteratorExt::map_err
+/// [`TryIteratorExt`]: crate::TryIteratorExt
+#[derive(Clone)]
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct MapErr<I, F> {
+    iter: I,
+    f: F,
+}
+
+impl<I, F> MapErr<I, F> {
+    pub(crate) fn new(iter: I, f: F) -> Self {
+        Self { iter, f }
+    }
+}
+
+impl<I: fmt::Debug, F> fmt::Debug for MapErr<I, F> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("MapErr").field("iter", &self.iter).finish()
+    }
+}
+
+impl<I, F, E> Iterator for MapErr<I, F>
+where
+    I: TryIterator,
+    F: FnMut(I::Err) -> E,
+{
+    type Item = Result<I::Ok, E>;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        self.iter.next().map(|result| result.map_err(&mut self.f))
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+impl<I, F, E> DoubleEndedIterator for MapErr<I, F>
+where
+    I: TryIterator + DoubleEndedIterator,
+    F: FnMut(I::Err) -> E,
+{
+    #[inline]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.iter
+            .next_back()
+            .map(|result| result.map_err(&mut self.f))
+    }
+}
+
+impl<I, F, E> ExactSizeIterator for MapErr<I, F>
+where
+    I: TryIterator + ExactSizeIterator,
+    F: FnMut(I::Err) -> E,
+{
+}
+
+impl<I, F, E> FusedIterator for MapErr<I, F>
+where
+    I: TryIterator + FusedIterator,
+    F: FnMut(I::Err) -> E,
+{
+}
+
+/// An iterator that filters and maps the success values of the underlying
+/// iterator with a fallible closure.
+///
+/// This `struct` is created by the [`try_filter_map`] method on
+/// [`TryIteratorExt`]. See its documentation for more.
+///
+/// [`try_filter_map`]: crate::TryIteratorExt::try_filter_map
+/// [`TryIteratorExt`]: crate::TryIteratorExt
+#[derive(Clone)]
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct TryFilterMap<I, F> {
+    iter: I,
+    f: F,
+}
+
+impl<I, F> TryFilterMap<I, F> {
+    pub(crate) fn new(iter: I, f: F) -> Self {
+        Self { iter, f }
+    }
+}
+
+impl<I: fmt::Debug, F> fmt::Debug for TryFilterMap<I, F> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("TryFilterMap")
+            .field("iter", &self.iter)
+            .finish()
+    }
+}
+
+impl<I, F, T> Iterator for TryFilterMap<I, F>
+where
+    I: TryIterator,
+    F: FnMut(I::Ok) -> Result<Option<T>, I::Err>,
+{
+    type Item = Result<T, I::Err>;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        for result in self.iter.by_ref() {
+            match result {
+                Ok(ok) => {
+                    if let Some(result) = (self.f)(ok).transpose() {
+                        return Some(result);
+                    }
+                }
+                Err(err) => return Some(Err(err)),
+            }
+        }
+        None
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let (_, upper) = self.iter.size_hint();
+        (0, upper)
+    }
+}
+
+impl<I, F, T> DoubleEndedIterator for TryFilterMap<I, F>
+where
+    I: TryIterator + DoubleEndedIterator,
+    F: FnMut(I::Ok) -> Result<Option<T>, I::Err>,
+{
+    #[inline]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        while let Some(result) = self.iter.next_back() {
+            match result {
+                Ok(ok) => {
+                    if let Some(result) = (self.f)(ok).transpose() {
+                        return Some(result);
+                    }
+                }
+                Err(err) => return Some(Err(err)),
+            }
+        }
+        None
+    }
+}
+
+impl<I, F, T> FusedIterator for TryFilterMap<I, F>
+where
+    I: TryIterator + FusedIterator,
+    F: FnMut(I::Ok) -> Result<Option<T>, I::Err>,
+{
+}
+
+/// An iterator that filters the success values of the underlying iterator
+/// with a fallible predicate.
+///
+/// This `struct` is created by the [`try_filter`] method on
+/// [`TryIteratorExt`]. See its documentation for more.
+///
+/// [`try_filter`]: crate::TryIteratorExt::try_filter
+/// [`TryIteratorExt`]: crate::TryIteratorExt
+#[derive(Clone)]
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct TryFilter<I, P> {
+    iter: I,
+    predicate: P,
+}
+
+impl<I, P> TryFilter<I, P> {
+    pub(crate) fn new(iter: I, predicate: P) -> Self {
+        Self { iter, predicate }
+    }
+}
+
+impl<I: fmt::Debug, P> fmt::Debug for TryFilter<I, P> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("TryFilter")
+            .field("iter", &self.iter)
+            .finish()
+    }
+}
+
+impl<I, P> Iterator for TryFilter<I, P>
+where
+    I: TryIterator,
+    P: FnMut(&I::Ok) -> Result<bool, I::Err>,
+{
+    type Item = I::Item;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        for result in self.iter.by_ref() {
+            match result {
+                Ok(ok) => match (self.predicate)(&ok) {
+                    Ok(true) => return Some(Ok(ok)),
+                    Ok(false) => {}
+                    Err(err) => return Some(Err(err)),
+                },
+                Err(err) => return Some(Err(err)),
+            }
+        }
+        None
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let (_, upper) = self.iter.size_hint();
+        (0, upper)
+    }
+}
+
+impl<I, P> DoubleEndedIterator for TryFilter<I, P>
+where
+    I: TryIterator + DoubleEndedIterator,
+    P: FnMut(&I::Ok) -> Result<bool, I::Err>,
+{
+    #[inline]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        while let Some(result) = self.iter.next_back() {
+            match result {
+                Ok(ok) => match (self.predicate)(&ok) {
+                    Ok(true) => return Some(Ok(ok)),
+                    Ok(false) => {}
+                    Err(err) => return Some(Err(err)),
+                },
+                Err(err) => return Some(Err(err)),
+            }
+        }
+        None
+    }
+}
+
+impl<I, P> FusedIterator for TryFilter<I, P>
+where
+    I: TryIterator + FusedIterator,
+    P: FnMut(&I::Ok) -> Result<bool, I::Err>,
+{
+}
+
+/// An iterator that maps the success values of the underlying iterator with
+/// a fallible closure holding internal state, until the closure returns
+/// `Ok(None)`.
+///
+/// This `struct` is created by the [`try_scan`] method on [`TryIteratorExt`].
+/// See its documentation for more.
+///
+/// [`try_scan`]: crate::TryIteratorExt::try_scan
+/// [`TryIteratorExt`]: crate::TryIteratorExt
+#[derive(Clone)]
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct TryScan<I, S, F> {
+    iter: Fuse<I>,
+    done: bool,
+    state: S,
+    f: F,
+}
+
+impl<I: Iterator, S, F> TryScan<I, S, F> {
+    pub(crate) fn new(iter: I, state: S, f: F) -> Self {
+        Self {
+            iter: iter.fuse(),
+            done: false,
+            state,
+            f,
+        }
+    }
+}
+
+impl<I: fmt::Debug, S: fmt::Debug, F> fmt::Debug for TryScan<I, S, F> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("TryScan")
+            .field("iter", &self.iter)
+            .field("done", &self.done)
+            .field("state", &self.state)
+            .finish()
+    }
+}
+
+impl<I, S, F, T> Iterator for TryScan<I, S, F>
+where
+    I: TryIterator,
+    F: FnMut(&mut S, I::Ok) -> Result<Option<T>, I::Err>,
+{
+    type Item = Result<T, I::Err>;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.done {
+            return None;
+        }
+        let next = match self.iter.next()? {
+            Ok(ok) => (self.f)(&mut self.state, ok).transpose(),
+            Err(err) => Some(Err(err)),
+        };
+        self.done = next.is_none();
+        next
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        if self.done {
+            (0, Some(0))
+        } else {
+            let (_, upper) = self.iter.size_hint();
+            (0, upper)
+        }
+    }
+}
+
+impl<I, S, F, T> FusedIterator for TryScan<I, S, F>
+where
+    I: TryIterator,
+    F: FnMut(&mut S, I::Ok) -> Result<Option<T>, I::Err>,
+{
+}
+
+/// An iterator that maps the success values of the underlying iterator with
+/// a fallible closure, until the closure returns `Ok(None)`.
+///
+/// This `struct` is created by the [`try_map_while`] method on
+/// [`TryIteratorExt`]. See its documentation for more.
+///
+/// [`try_map_while`]: crate::TryIteratorExt::try_map_while
+/// [`TryIteratorExt`]: crate::TryIteratorExt
+#[derive(Clone)]
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct TryMapWhile<I, F> {
+    iter: Fuse<I>,
+    done: bool,
+    f: F,
+}
+
+impl<I: Iterator, F> TryMapWhile<I, F> {
+    pub(crate) fn new(iter: I, f: F) -> Self {
+        Self {
+            iter: iter.fuse(),
+            done: false,
+            f,
+        }
+    }
+}
+
+impl<I: fmt::Debug, F> fmt::Debug for TryMapWhile<I, F> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("TryMapWhile")
+            .field("iter", &self.iter)
+            .field("done", &self.done)
+            .finish()
+    }
+}
+
+impl<I, F, T> Iterator for TryMapWhile<I, F>
+where
+    I: TryIterator,
+    F: FnMut(I::Ok) -> Result<Option<T>, I::Err>,
+{
+    type Item = Result<T, I::Err>;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.done {
+            return None;
+        }
+        let next = match self.iter.next()? {
+            Ok(ok) => (self.f)(ok).transpose(),
+            Err(err) => Some(Err(err)),
+        };
+        self.done = next.is_none();
+        next
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        if self.done {
+            (0, Some(0))
+        } else {
+            let (_, upper) = self.iter.size_hint();
+            (0, upper)
+        }
+    }
+}
+
+impl<I, F, T> FusedIterator for TryMapWhile<I, F>
+where
+    I: TryIterator,
+    F: FnMut(I::Ok) -> Result<Option<T>, I::Err>,
+{
+}
+
+/// An iterator that yields success values while a fallible predicate
+/// returns `Ok(true)`, passing errors through.
+///
+/// This `struct` is created by the [`try_take_while`] method on
+/// [`TryIteratorExt`]. See its documentation for more.
+///
+/// [`try_take_while`]: crate::TryIteratorExt::try_take_while
+/// [`TryIteratorExt`]: crate::TryIteratorExt
+#[derive(Clone)]
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct TryTakeWhile<I, P> {
+    iter: Fuse<I>,
+    done: bool,
+    predicate: P,
+}
+
+impl<I: Iterator, P> TryTakeWhile<I, P> {
+    pub(crate) fn new(iter: I, predicate: P) -> Self {
+        Self {
+            iter: iter.fuse(),
+            done: false,
+            predicate,
+        }
+    }
+}
+
+impl<I: fmt::Debug, P> fmt::Debug for TryTakeWhile<I, P> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("TryTakeWhile")
+            .field("iter", &self.iter)
+            .field("done", &self.done)
+            .finish()
+    }
+}
+
+impl<I, P> Iterator for TryTakeWhile<I, P>
+where
+    I: TryIterator,
+    P: FnMut(&I::Ok) -> Result<bool, I::Err>,
+{
+    type Item = I::Item;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.done {
+            return None;
+        }
+        match self.iter.next()? {
+            Ok(ok) => match (self.predicate)(&ok) {
+                Ok(true) => Some(Ok(ok)),
+                Ok(false) => {
+                    self.done = true;
+                    None
+                }
+                Err(err) => {
+                    self.done = true;
+                    Some(Err(err))
+                }
+            },
+            Err(err) => Some(Err(err)),
+        }
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        if self.done {
+            (0, Some(0))
+        } else {
+            let (_, upper) = self.iter.size_hint();
+            (0, upper)
+        }
+    }
+}
+
+impl<I, P> FusedIterator for TryTakeWhile<I, P>
+where
+    I: TryIterator,
+    P: FnMut(&I::Ok) -> Result<bool, I::Err>,
+{
+}
+
+/// An iterator that skips success values while a fallible predicate returns
+/// `Ok(true)`, passing errors through.
+///
+/// This `struct` is created by the [`try_skip_while`] method on
+/// [`TryIteratorExt`]. See its documentation for more.
+///
+/// [`try_skip_while`]: crate::TryIteratorExt::try_skip_while
+/// [`TryIteratorExt`]: crate::TryIteratorExt
+#[derive(Clone)]
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct TrySkipWhile<I, P> {
+    iter: Fuse<I>,
+    skipping: bool,
+    predicate: P,
+}
+
+impl<I: Iterator, P> TrySkipWhile<I, P> {
+    pub(crate) fn new(iter: I, predicate: P) -> Self {
+        Self {
+            iter: iter.fuse(),
+            skipping: true,
+            predicate,
+        }
+    }
+}
+
+impl<I: fmt::Debug, P> fmt::Debug for TrySkipWhile<I, P> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("TrySkipWhile")
+            .field("iter", &self.iter)
+            .field("skipping", &self.skipping)
+            .finish()
+    }
+}
+
+impl<I, P> Iterator for TrySkipWhile<I, P>
+where
+    I: TryIterator,
+    P: FnMut(&I::Ok) -> Result<bool, I::Err>,
+{
+    type Item = I::Item;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        if !self.skipping {
+            return self.iter.next();
+        }
+        for result in self.iter.by_ref() {
+            match result {
+                Ok(ok) => match (self.predicate)(&ok) {
+                    Ok(true) => {}
+                    Ok(false) => {
+                        self.skipping = false;
+                        return Some(Ok(ok));
+                    }
+                    Err(err) => {
+                        self.skipping = false;
+                        return Some(Err(err));
+                    }
+                },
+                Err(err) => return Some(Err(err)),
+            }
+        }
+        None
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let (lower, upper) = self.iter.size_hint();
+        if self.skipping {
+            (0, upper)
+        } else {
+            (lower, upper)
+        }
+    }
+}
+
+impl<I, P> FusedIterator for TrySkipWhile<I, P>
+where
+    I: TryIterator,
+    P: FnMut(&I::Ok) -> Result<bool, I::Err>,
+{
+}
+
+/// An iterator that calls a closure with a reference to each success value
+/// before passing it on.
+///
+/// This `struct` is created by the [`inspect_ok`] method on
+/// [`TryIteratorExt`]. See its documentation for more.
+///
+/// [`inspect_ok`]: crate::TryIteratorExt::inspect_ok
+/// [`TryIteratorExt`]: crate::TryIteratorExt
+#[derive(Clone)]
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct InspectOk<I, F> {
+    iter: I,
+    f: F,
+}
+
+impl<I, F> InspectOk<I, F> {
+    pub(crate) fn new(iter: I, f: F) -> Self {
+        Self { iter, f }
+    }
+}
+
+impl<I: fmt::Debug, F> fmt::Debug for InspectOk<I, F> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("InspectOk")
+            .field("iter", &self.iter)
+            .finish()
+    }
+}
+
+impl<I, F> Iterator for InspectOk<I, F>
+where
+    I: TryIterator,
+    F: FnMut(&I::Ok),
+{
+    type Item = I::Item;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        let item = self.iter.next();
+        if let Some(Ok(ok)) = &item {
+            (self.f)(ok);
+        }
+        item
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+impl<I, F> DoubleEndedIterator for InspectOk<I, F>
+where
+    I: TryIterator + DoubleEndedIterator,
+    F: FnMut(&I::Ok),
+{
+    #[inline]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        let item = self.iter.next_back();
+        if let Some(Ok(ok)) = &item {
+            (self.f)(ok);
+        }
+        item
+    }
+}
+
+impl<I, F> ExactSizeIterator for InspectOk<I, F>
+where
+    I: TryIterator + ExactSizeIterator,
+    F: FnMut(&I::Ok),
+{
+}
+
+impl<I, F> FusedIterator for InspectOk<I, F>
+where
+    I: TryIterator + FusedIterator,
+    F: FnMut(&I::Ok),
+{
+}
+
+/// An iterator that calls a closure with a reference to each error value
+/// before passing it on.
+///
+/// This `struct` is created by the [`inspect_err`] method on
+/// [`TryIteratorExt`]. See its documentation for more.
+///
+/// [`inspect_err`]: crate::TryIteratorExt::inspect_err
+/// [`TryIteratorExt`]: crate::TryIteratorExt
+#[derive(Clone)]
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct InspectErr<I, F> {
+    iter: I,
+    f: F,
+}
+
+impl<I, F> InspectErr<I, F> {
+    pub(crate) fn new(iter: I, f: F) -> Self {
+        Self { iter, f }
+    }
+}
+
+impl<I: fmt::Debug, F> fmt::Debug for InspectErr<I, F> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("InspectErr")
+            .field("iter", &self.iter)
+            .finish()
+    }
+}
+
+impl<I, F> Iterator for InspectErr<I, F>
+where
+    I: TryIterator,
+    F: FnMut(&I::Err),
+{
+    type Item = I::Item;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        let item = self.iter.next();
+        if let Some(Err(err)) = &item {
+            (self.f)(err);
+        }
+        item
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+impl<I, F> DoubleEndedIterator for InspectErr<I, F>
+where
+    I: TryIterator + DoubleEndedIterator,
+    F: FnMut(&I::Err),
+{
+    #[inline]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        let item = self.iter.next_back();
+        if let Some(Err(err)) = &item {
+            (self.f)(err);
+        }
+        item
+    }
+}
+
+impl<I, F> ExactSizeIterator for InspectErr<I, F>
+where
+    I: TryIterator + ExactSizeIterator,
+    F: FnMut(&I::Err),
+{
+}
+
+impl<I, F> FusedIterator for InspectErr<I, F>
+where
+    I: TryIterator + FusedIterator,
+    F: FnMut(&I::Err),
+{
+}
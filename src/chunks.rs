@@ -0,0 +1,338 @@
+use alloc::vec::Vec;
+use core::fmt;
+use core::iter::FusedIterator;
+use core::marker::PhantomData;
+use core::mem;
+
+use crate::TryIterator;
+
+/// Selects what a chunking adapter does with a partially filled chunk when
+/// an error arrives.
+///
+/// This trait is sealed and implemented by [`FlushPartial`],
+/// [`DiscardPartial`] and [`AttachPartial`].
+pub trait PartialChunkPolicy<T, E>: private::Sealed {
+    /// The error type yielded by the adapter.
+    type Error;
+
+    /// Whether a partial chunk is yielded before the error.
+    #[doc(hidden)]
+    const FLUSH: bool;
+
+    /// Builds the yielded error from the partial chunk, which is empty if
+    /// it was flushed.
+    #[doc(hidden)]
+    fn error(partial: Vec<T>, error: E) -> Self::Error;
+}
+
+/// A [`PartialChunkPolicy`] usable with [`try_array_chunks`], which can not
+/// yield a partial chunk.
+///
+/// [`try_array_chunks`]: crate::TryIteratorExt::try_array_chunks
+pub trait ArrayChunkPolicy<T, E>: PartialChunkPolicy<T, E> {}
+
+/// The default [`PartialChunkPolicy`] of [`try_chunks`]: a partial chunk is
+/// yielded as a shorter chunk, followed by the error.
+///
+/// [`try_chunks`]: crate::TryIteratorExt::try_chunks
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct FlushPartial;
+
+/// A [`PartialChunkPolicy`] which drops a partial chunk and only yields the
+/// error. This is the default of [`try_array_chunks`].
+///
+/// [`try_array_chunks`]: crate::TryIteratorExt::try_array_chunks
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct DiscardPartial;
+
+/// A [`PartialChunkPolicy`] which yields the error wrapped in a
+/// [`ChunkError`] holding the partial chunk.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct AttachPartial;
+
+impl<T, E> PartialChunkPolicy<T, E> for FlushPartial {
+    type Error = E;
+    const FLUSH: bool = true;
+
+    fn error(_partial: Vec<T>, error: E) -> E {
+        error
+    }
+}
+
+impl<T, E> PartialChunkPolicy<T, E> for DiscardPartial {
+    type Error = E;
+    const FLUSH: bool = false;
+
+    fn error(_partial: Vec<T>, error: E) -> E {
+        error
+    }
+}
+
+impl<T, E> PartialChunkPolicy<T, E> for AttachPartial {
+    type Error = ChunkError<T, E>;
+    const FLUSH: bool = false;
+
+    fn error(partial: Vec<T>, error: E) -> ChunkError<T, E> {
+        ChunkError { partial, error }
+    }
+}
+
+impl<T, E> ArrayChunkPolicy<T, E> for DiscardPartial {}
+impl<T, E> ArrayChunkPolicy<T, E> for AttachPartial {}
+
+mod private {
+    pub trait Sealed {}
+    impl Sealed for super::FlushPartial {}
+    impl Sealed for super::DiscardPartial {}
+    impl Sealed for super::AttachPartial {}
+}
+
+/// An error yielded by a chunking adapter with the [`AttachPartial`] policy,
+/// holding the elements of the chunk it interrupted.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct ChunkError<T, E> {
+    partial: Vec<T>,
+    error: E,
+}
+
+impl<T, E> ChunkError<T, E> {
+    /// The elements of the chunk interrupted by the error, possibly none.
+    pub fn partial(&self) -> &[T] {
+        &self.partial
+    }
+
+    /// A reference to the underlying error.
+    pub fn error(&self) -> &E {
+        &self.error
+    }
+
+    /// Consumes this wrapper, returning the partial chunk and the error.
+    pub fn into_parts(self) -> (Vec<T>, E) {
+        (self.partial, self.error)
+    }
+}
+
+impl<T, E: fmt::Display> fmt::Display for ChunkError<T, E> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "chunk interrupted after {} elements: {}",
+            self.partial.len(),
+            self.error
+        )
+    }
+}
+
+impl<T, E> core::error::Error for ChunkError<T, E>
+where
+    T: fmt::Debug,
+    E: core::error::Error + 'static,
+{
+    fn source(&self) -> Option<&(dyn core::error::Error + 'static)> {
+        Some(&self.error)
+    }
+}
+
+/// An iterator over the success values of the underlying iterator in
+/// chunks of `size` elements, passing errors through.
+///
+/// This `struct` is created by the [`try_chunks`] method on
+/// [`TryIteratorExt`]. See its documentation for more.
+///
+/// [`try_chunks`]: crate::TryIteratorExt::try_chunks
+/// [`TryIteratorExt`]: crate::TryIteratorExt
+#[derive(Clone, Debug)]
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct TryChunks<I: TryIterator, P = FlushPartial> {
+    iter: I,
+    size: usize,
+    buf: Vec<I::Ok>,
+    /// An error to yield after a flushed partial chunk.
+    pending: Option<I::Err>,
+    policy: PhantomData<P>,
+}
+
+impl<I: TryIterator> TryChunks<I> {
+    pub(crate) fn new(iter: I, size: usize) -> Self {
+        assert!(size != 0, "chunk size must be non-zero");
+        Self {
+            iter,
+            size,
+            buf: Vec::new(),
+            pending: None,
+            policy: PhantomData,
+        }
+    }
+
+    /// Switches to the [`DiscardPartial`] policy: a partial chunk
+    /// interrupted by an error is dropped.
+    pub fn discard_partial(self) -> TryChunks<I, DiscardPartial> {
+        self.with_policy()
+    }
+
+    /// Switches to the [`AttachPartial`] policy: errors are yielded as a
+    /// [`ChunkError`] holding the partial chunk they interrupted.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tryiter::TryIteratorExt;
+    ///
+    /// let mut chunks = vec![Ok(1), Ok(2), Ok(3), Err("error"), Ok(4)]
+    ///     .into_iter()
+    ///     .try_chunks(2)
+    ///     .attach_partial();
+    ///
+    /// assert_eq!(chunks.next(), Some(Ok(vec![1, 2])));
+    /// let err = chunks.next().unwrap().unwrap_err();
+    /// assert_eq!(err.into_parts(), (vec![3], "error"));
+    /// assert_eq!(chunks.next(), Some(Ok(vec![4])));
+    /// ```
+    pub fn attach_partial(self) -> TryChunks<I, AttachPartial> {
+        self.with_policy()
+    }
+
+    fn with_policy<P>(self) -> TryChunks<I, P> {
+        TryChunks {
+            iter: self.iter,
+            size: self.size,
+            buf: self.buf,
+            pending: self.pending,
+            policy: PhantomData,
+        }
+    }
+}
+
+impl<I, P> Iterator for TryChunks<I, P>
+where
+    I: TryIterator,
+    P: PartialChunkPolicy<I::Ok, I::Err>,
+{
+    type Item = Result<Vec<I::Ok>, P::Error>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if let Some(e) = self.pending.take() {
+            return Some(Err(P::error(Vec::new(), e)));
+        }
+        loop {
+            match self.iter.next() {
+                Some(Ok(v)) => {
+                    self.buf.push(v);
+                    if self.buf.len() == self.size {
+                        return Some(Ok(mem::take(&mut self.buf)));
+                    }
+                }
+                Some(Err(e)) => {
+                    let partial = mem::take(&mut self.buf);
+                    if P::FLUSH && !partial.is_empty() {
+                        self.pending = Some(e);
+                        return Some(Ok(partial));
+                    }
+                    return Some(Err(P::error(partial, e)));
+                }
+                None if self.buf.is_empty() => return None,
+                None => return Some(Ok(mem::take(&mut self.buf))),
+            }
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let (lo, hi) = self.iter.size_hint();
+        let buffered = self.buf.len() + usize::from(self.pending.is_some());
+        let lo = usize::from(lo.saturating_add(buffered) > 0);
+        (lo, hi.and_then(|hi| hi.checked_add(buffered)))
+    }
+}
+
+/// An iterator over the success values of the underlying iterator in
+/// arrays of `N` elements, passing errors through.
+///
+/// This `struct` is created by the [`try_array_chunks`] method on
+/// [`TryIteratorExt`]. See its documentation for more.
+///
+/// [`try_array_chunks`]: crate::TryIteratorExt::try_array_chunks
+/// [`TryIteratorExt`]: crate::TryIteratorExt
+#[derive(Clone, Debug)]
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct TryArrayChunks<I: TryIterator, const N: usize, P = DiscardPartial> {
+    iter: I,
+    buf: Vec<I::Ok>,
+    policy: PhantomData<P>,
+}
+
+impl<I: TryIterator, const N: usize> TryArrayChunks<I, N> {
+    pub(crate) fn new(iter: I) -> Self {
+        assert!(N != 0, "chunk size must be non-zero");
+        Self {
+            iter,
+            buf: Vec::with_capacity(N),
+            policy: PhantomData,
+        }
+    }
+
+    /// Switches to the [`AttachPartial`] policy: errors are yielded as a
+    /// [`ChunkError`] holding the partial chunk they interrupted.
+    pub fn attach_partial(self) -> TryArrayChunks<I, N, AttachPartial> {
+        TryArrayChunks {
+            iter: self.iter,
+            buf: self.buf,
+            policy: PhantomData,
+        }
+    }
+}
+
+impl<I: TryIterator, const N: usize, P> TryArrayChunks<I, N, P> {
+    /// The elements left over once the underlying iterator is exhausted,
+    /// which were too few to fill an array.
+    pub fn remainder(&self) -> &[I::Ok] {
+        &self.buf
+    }
+}
+
+impl<I, const N: usize, P> Iterator for TryArrayChunks<I, N, P>
+where
+    I: TryIterator,
+    P: ArrayChunkPolicy<I::Ok, I::Err>,
+{
+    type Item = Result<[I::Ok; N], P::Error>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            match self.iter.next()? {
+                Ok(v) => {
+                    self.buf.push(v);
+                    if self.buf.len() == N {
+                        let chunk = mem::replace(&mut self.buf, Vec::with_capacity(N));
+                        match chunk.try_into() {
+                            Ok(array) => return Some(Ok(array)),
+                            Err(_) => unreachable!("the chunk holds exactly N elements"),
+                        }
+                    }
+                }
+                Err(e) => {
+                    let partial = mem::replace(&mut self.buf, Vec::with_capacity(N));
+                    return Some(Err(P::error(partial, e)));
+                }
+            }
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let (_, hi) = self.iter.size_hint();
+        (0, hi)
+    }
+}
+
+impl<I, P> FusedIterator for TryChunks<I, P>
+where
+    I: TryIterator + FusedIterator,
+    P: PartialChunkPolicy<I::Ok, I::Err>,
+{
+}
+
+impl<I, const N: usize, P> FusedIterator for TryArrayChunks<I, N, P>
+where
+    I: TryIterator + FusedIterator,
+    P: ArrayChunkPolicy<I::Ok, I::Err>,
+{
+}
@@ -0,0 +1,75 @@
+use core::iter;
+
+use crate::IndexedError;
+
+/// A container which accumulates the errors collected by
+/// [`try_collect_all_errors`].
+///
+/// This trait is implemented for every type which is `Default + Extend<E>`,
+/// such as `Vec<E>`. Containers which cannot be empty, and thus cannot
+/// implement [`Default`], can implement this trait directly.
+///
+/// [`try_collect_all_errors`]: crate::TryIteratorExt::try_collect_all_errors
+pub trait ErrorCollector<E>: Sized {
+    /// Creates a container holding the first error encountered.
+    fn from_error(error: E) -> Self;
+
+    /// Adds a subsequent error to the container.
+    fn push_error(&mut self, error: E);
+}
+
+impl<E, C> ErrorCollector<E> for C
+where
+    C: Default + Extend<E>,
+{
+    fn from_error(error: E) -> Self {
+        let mut container = Self::default();
+        container.push_error(error);
+        container
+    }
+
+    fn push_error(&mut self, error: E) {
+        self.extend(iter::once(error));
+    }
+}
+
+pub(crate) fn collect_all_errors<I, O, E, C, Errs>(iter: I) -> Result<C, Errs>
+where
+    I: Iterator<Item = Result<O, E>>,
+    C: FromIterator<O>,
+    Errs: ErrorCollector<IndexedError<E>>,
+{
+    let mut iter = iter.enumerate();
+    let mut errors: Option<Errs> = None;
+
+    // Feed success values to the collection until the first error, after
+    // which the rest of the iterator is only scanned for errors.
+    let collected = iter::from_fn(|| {
+        if errors.is_some() {
+            return None;
+        }
+        match iter.next()? {
+            (_, Ok(value)) => Some(value),
+            (index, Err(error)) => {
+                errors = Some(Errs::from_error(IndexedError::new(index, error)));
+                None
+            }
+        }
+    })
+    .collect::<C>();
+
+    for (index, result) in iter {
+        if let Err(error) = result {
+            let error = IndexedError::new(index, error);
+            match errors {
+                Some(ref mut errors) => errors.push_error(error),
+                None => errors = Some(Errs::from_error(error)),
+            }
+        }
+    }
+
+    match errors {
+        Some(errors) => Err(errors),
+        None => Ok(collected),
+    }
+}
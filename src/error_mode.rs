@@ -0,0 +1,76 @@
+use core::convert::Infallible;
+
+/// Selects what a peeking adapter does with an error it encounters while
+/// looking ahead.
+///
+/// This trait is sealed and implemented by [`ForwardErrors`] and
+/// [`StashErrors`].
+pub trait ErrorMode<E>: private::Sealed {
+    /// The type an error is stored as while peeking. It is uninhabited when
+    /// errors are never stored.
+    #[doc(hidden)]
+    type Stored;
+
+    /// Returns `Ok` with the error to store in place, or `Err(error)` if it
+    /// should be returned to the caller and dropped.
+    #[doc(hidden)]
+    fn stash(error: E) -> Result<Self::Stored, E>;
+
+    /// Produces a copy of a stored error to return from a peek.
+    #[doc(hidden)]
+    fn replay(stored: &Self::Stored) -> E;
+
+    /// Takes a stored error back out to yield it.
+    #[doc(hidden)]
+    fn restore(stored: Self::Stored) -> E;
+}
+
+/// The default [`ErrorMode`]: an error encountered while peeking is returned
+/// by the peek and not stored, so the next peek continues with the following
+/// element.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct ForwardErrors;
+
+/// An [`ErrorMode`] in which an error encountered while peeking is stored in
+/// place. Peeking at it again returns a clone of the same error, and it is
+/// yielded by `next()` in its original position.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct StashErrors;
+
+impl<E> ErrorMode<E> for ForwardErrors {
+    type Stored = Infallible;
+
+    fn stash(error: E) -> Result<Infallible, E> {
+        Err(error)
+    }
+
+    fn replay(stored: &Infallible) -> E {
+        match *stored {}
+    }
+
+    fn restore(stored: Infallible) -> E {
+        match stored {}
+    }
+}
+
+impl<E: Clone> ErrorMode<E> for StashErrors {
+    type Stored = E;
+
+    fn stash(error: E) -> Result<E, E> {
+        Ok(error)
+    }
+
+    fn replay(stored: &E) -> E {
+        stored.clone()
+    }
+
+    fn restore(stored: E) -> E {
+        stored
+    }
+}
+
+mod private {
+    pub trait Sealed {}
+    impl Sealed for super::ForwardErrors {}
+    impl Sealed for super::StashErrors {}
+}
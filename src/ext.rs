@@ -1,6 +1,18 @@
-use std::iter;
-
-use crate::{TryIterator, TryPeekable};
+use crate::{
+    collect_errors, reduce, CheckedNum, ContextErr, ErrInto, ErrorCollector, ErrorPolicy,
+    ErrorSlot, FuseOnErr, IndexedError, InspectErr, InspectOk, MapErr, MapOk, MinMaxResult,
+    ReduceError, Shunt, TolerateErrors, TryFilter, TryFilterMap, TryFlatMap, TryFlatten,
+    TryIterator, TryMapWhile, TryPeekable, TryScan, TrySkipWhile, TryTakeWhile, WithIndexContext,
+};
+#[cfg(feature = "alloc")]
+use crate::{
+    partition, sorted, PartitionErr, PartitionOk, TryArrayChunks, TryChunks, TryMultiPeek,
+    TryPutBack, TryTupleWindows, TryWindows, TupleWindow,
+};
+#[cfg(feature = "external-sort")]
+use crate::{ExternalSort, ExternalSortError, ExternalSorted, SortCodec};
+#[cfg(feature = "alloc")]
+use alloc::vec::{self, Vec};
 
 pub trait TryIteratorExt: TryIterator {
     /// Attempt to retrieve the next value from the iterator, lifting the error
@@ -20,6 +32,26 @@ pub trait TryIteratorExt: TryIterator {
         self.next().transpose()
     }
 
+    /// Attempt to retrieve the next value from the back of the iterator,
+    /// lifting the error if one occurs.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tryiter::TryIteratorExt;
+    ///
+    /// let mut iter = vec![Err(5), Ok(5)].into_iter();
+    ///
+    /// assert_eq!(iter.try_next_back(), Ok(Some(5)));
+    /// assert_eq!(iter.try_next_back(), Err(5));
+    /// ```
+    fn try_next_back(&mut self) -> Result<Option<Self::Ok>, Self::Err>
+    where
+        Self: DoubleEndedIterator,
+    {
+        self.next_back().transpose()
+    }
+
     /// Wraps the current iterator in a new iterator that converts the error
     /// type into the one provided.
     ///
@@ -33,12 +65,12 @@ pub trait TryIteratorExt: TryIterator {
     /// assert_eq!(iter.next(), Some(Ok(())));
     /// assert_eq!(iter.next(), Some(Err(5i64)));
     /// ```
-    fn err_into<E>(self) -> impl TryIterator<Ok = Self::Ok, Err = E>
+    fn err_into<E>(self) -> ErrInto<Self, E>
     where
         Self: Sized,
         Self::Err: Into<E>,
     {
-        self.map(|result| result.map_err(Into::into))
+        ErrInto::new(self)
     }
 
     /// Wraps the current iterator in a new iterator that maps the success value
@@ -54,12 +86,12 @@ pub trait TryIteratorExt: TryIterator {
     /// assert_eq!(iter.next(), Some(Ok(10)));
     /// assert_eq!(iter.next(), Some(Err(5)));
     /// ```
-    fn map_ok<T, F>(mut self, mut f: F) -> impl TryIterator<Ok = T, Err = Self::Err>
+    fn map_ok<T, F>(self, f: F) -> MapOk<Self, F>
     where
         Self: Sized,
         F: FnMut(Self::Ok) -> Result<T, Self::Err>,
     {
-        iter::from_fn(move || self.next().map(|result| result.and_then(&mut f)))
+        MapOk::new(self, f)
     }
 
     /// Wraps the current iterator in a new iterator that maps the error value
@@ -75,12 +107,12 @@ pub trait TryIteratorExt: TryIterator {
     /// assert_eq!(iter.next(), Some(Ok(5)));
     /// assert_eq!(iter.next(), Some(Err(10)));
     /// ```
-    fn map_err<E, F>(mut self, mut f: F) -> impl TryIterator<Ok = Self::Ok, Err = E>
+    fn map_err<E, F>(self, f: F) -> MapErr<Self, F>
     where
         Self: Sized,
         F: FnMut(Self::Err) -> E,
     {
-        iter::from_fn(move || self.next().map(|result| result.map_err(&mut f)))
+        MapErr::new(self, f)
     }
 
     /// Wraps the current iterator in a new iterator that filters and maps the
@@ -100,15 +132,12 @@ pub trait TryIteratorExt: TryIterator {
     /// assert_eq!(halves.next(), Some(Ok(3)));
     /// assert_eq!(halves.next(), Some(Err("error")));
     /// ```
-    fn try_filter_map<T, F>(self, mut f: F) -> impl TryIterator<Ok = T, Err = Self::Err>
+    fn try_filter_map<T, F>(self, f: F) -> TryFilterMap<Self, F>
     where
         Self: Sized,
         F: FnMut(Self::Ok) -> Result<Option<T>, Self::Err>,
     {
-        self.filter_map(move |result| match result {
-            Ok(ok) => f(ok).transpose(),
-            Err(err) => Some(Err(err)),
-        })
+        TryFilterMap::new(self, f)
     }
 
     /// Wraps the current iterator in a new iterator that filters the success
@@ -125,18 +154,321 @@ pub trait TryIteratorExt: TryIterator {
     /// assert_eq!(evens.next(), Some(Ok(2)));
     /// assert_eq!(evens.next(), Some(Err("error")));
     /// ```
-    fn try_filter<P>(self, mut predicate: P) -> impl TryIterator<Ok = Self::Ok, Err = Self::Err>
+    fn try_filter<P>(self, predicate: P) -> TryFilter<Self, P>
     where
         Self: Sized,
         P: FnMut(&Self::Ok) -> Result<bool, Self::Err>,
     {
-        self.try_filter_map(move |value| {
-            if predicate(&value)? {
-                Ok(Some(value))
-            } else {
-                Ok(None)
-            }
-        })
+        TryFilter::new(self, predicate)
+    }
+
+    /// Wraps the current iterator in a new iterator that maps the success
+    /// values using the provided closure and a mutable state, stopping for
+    /// good once the closure returns `Ok(None)`.
+    ///
+    /// Errors from the underlying iterator are passed through and errors
+    /// returned by the closure are yielded in their place.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tryiter::TryIteratorExt;
+    ///
+    /// let mut iter = vec![Ok(5), Err(5), Ok(3)]
+    ///     .into_iter()
+    ///     .try_scan(0, |total, x| {
+    ///         *total += x;
+    ///         Ok(Some(*total))
+    ///     });
+    ///
+    /// assert_eq!(iter.next(), Some(Ok(5)));
+    /// assert_eq!(iter.next(), Some(Err(5)));
+    /// assert_eq!(iter.next(), Some(Ok(8)));
+    /// ```
+    fn try_scan<S, T, F>(self, initial_state: S, f: F) -> TryScan<Self, S, F>
+    where
+        Self: Sized,
+        F: FnMut(&mut S, Self::Ok) -> Result<Option<T>, Self::Err>,
+    {
+        TryScan::new(self, initial_state, f)
+    }
+
+    /// Wraps the current iterator in a new iterator that maps the success
+    /// values using the provided closure, stopping for good once the closure
+    /// returns `Ok(None)`.
+    ///
+    /// Errors from the underlying iterator are passed through and errors
+    /// returned by the closure are yielded in their place.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tryiter::TryIteratorExt;
+    ///
+    /// let mut iter = vec![Ok(5), Err(5), Ok(-1), Ok(3)]
+    ///     .into_iter()
+    ///     .try_map_while(|x| Ok(u32::try_from(x).ok()));
+    ///
+    /// assert_eq!(iter.next(), Some(Ok(5)));
+    /// assert_eq!(iter.next(), Some(Err(5)));
+    /// assert_eq!(iter.next(), None);
+    /// assert_eq!(iter.next(), None);
+    /// ```
+    fn try_map_while<T, F>(self, f: F) -> TryMapWhile<Self, F>
+    where
+        Self: Sized,
+        F: FnMut(Self::Ok) -> Result<Option<T>, Self::Err>,
+    {
+        TryMapWhile::new(self, f)
+    }
+
+    /// Wraps the current iterator in a new iterator that yields success
+    /// values while the provided predicate returns `Ok(true)`.
+    ///
+    /// Errors from the underlying iterator are passed through without ending
+    /// the iteration. An error returned by the predicate is yielded in place
+    /// of the value it was called with, and ends the iteration like
+    /// `Ok(false)` does. The returned iterator is fused.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tryiter::TryIteratorExt;
+    ///
+    /// let mut iter = vec![Ok(1), Err("error"), Ok(2), Ok(5), Ok(3)]
+    ///     .into_iter()
+    ///     .try_take_while(|x| Ok(*x < 4));
+    ///
+    /// assert_eq!(iter.next(), Some(Ok(1)));
+    /// assert_eq!(iter.next(), Some(Err("error")));
+    /// assert_eq!(iter.next(), Some(Ok(2)));
+    /// assert_eq!(iter.next(), None);
+    /// ```
+    fn try_take_while<P>(self, predicate: P) -> TryTakeWhile<Self, P>
+    where
+        Self: Sized,
+        P: FnMut(&Self::Ok) -> Result<bool, Self::Err>,
+    {
+        TryTakeWhile::new(self, predicate)
+    }
+
+    /// Wraps the current iterator in a new iterator that skips success
+    /// values while the provided predicate returns `Ok(true)`, then yields
+    /// every remaining element.
+    ///
+    /// Errors from the underlying iterator encountered while skipping are
+    /// yielded and do not end the skipping phase. An error returned by the
+    /// predicate is yielded in place of the value it was called with, and
+    /// ends the skipping phase like `Ok(false)` does. The returned iterator
+    /// is fused.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tryiter::TryIteratorExt;
+    ///
+    /// let mut iter = vec![Ok(1), Err("error"), Ok(2), Ok(5), Ok(3)]
+    ///     .into_iter()
+    ///     .try_skip_while(|x| Ok(*x < 4));
+    ///
+    /// assert_eq!(iter.next(), Some(Err("error")));
+    /// assert_eq!(iter.next(), Some(Ok(5)));
+    /// assert_eq!(iter.next(), Some(Ok(3)));
+    /// assert_eq!(iter.next(), None);
+    /// ```
+    fn try_skip_while<P>(self, predicate: P) -> TrySkipWhile<Self, P>
+    where
+        Self: Sized,
+        P: FnMut(&Self::Ok) -> Result<bool, Self::Err>,
+    {
+        TrySkipWhile::new(self, predicate)
+    }
+
+    /// Wraps the current iterator in a new iterator that flattens success
+    /// values which are themselves iterators of results. Errors from both the
+    /// outer and the inner iterators are passed through.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tryiter::TryIteratorExt;
+    ///
+    /// let iter = vec![Ok(vec![Ok(1), Err("inner")]), Err("outer"), Ok(vec![Ok(2)])].into_iter();
+    /// let mut flat = iter.try_flatten();
+    ///
+    /// assert_eq!(flat.next(), Some(Ok(1)));
+    /// assert_eq!(flat.next(), Some(Err("inner")));
+    /// assert_eq!(flat.next(), Some(Err("outer")));
+    /// assert_eq!(flat.next(), Some(Ok(2)));
+    /// assert_eq!(flat.next(), None);
+    /// ```
+    fn try_flatten<T>(self) -> TryFlatten<Self>
+    where
+        Self: Sized,
+        Self::Ok: IntoIterator<Item = Result<T, Self::Err>>,
+    {
+        TryFlatten::new(self)
+    }
+
+    /// Wraps the current iterator in a new iterator that maps each success
+    /// value to an iterator of results using the provided closure, and
+    /// flattens the results. Errors from the underlying iterator, the closure,
+    /// and the nested iterators are passed through.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tryiter::TryIteratorExt;
+    ///
+    /// let iter = vec![Ok(2), Ok(-1), Err("error"), Ok(1)].into_iter();
+    /// let mut flat = iter.try_flat_map(|n| {
+    ///     if n < 0 {
+    ///         return Err("negative");
+    ///     }
+    ///     Ok((0..n).map(Ok))
+    /// });
+    ///
+    /// assert_eq!(flat.next(), Some(Ok(0)));
+    /// assert_eq!(flat.next(), Some(Ok(1)));
+    /// assert_eq!(flat.next(), Some(Err("negative")));
+    /// assert_eq!(flat.next(), Some(Err("error")));
+    /// assert_eq!(flat.next(), Some(Ok(0)));
+    /// assert_eq!(flat.next(), None);
+    /// ```
+    fn try_flat_map<T, U, F>(self, f: F) -> TryFlatMap<Self, U, F>
+    where
+        Self: Sized,
+        U: IntoIterator<Item = Result<T, Self::Err>>,
+        F: FnMut(Self::Ok) -> Result<U, Self::Err>,
+    {
+        TryFlatMap::new(self, f)
+    }
+
+    /// Groups the success values into chunks of `size` elements, passing
+    /// errors through. The last chunk may be shorter if the iterator runs
+    /// out of elements.
+    ///
+    /// By default a partial chunk interrupted by an error is yielded first,
+    /// followed by the error ([`FlushPartial`]). Use
+    /// [`TryChunks::discard_partial`] or [`TryChunks::attach_partial`] to
+    /// select another policy.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `size` is 0.
+    ///
+    /// [`FlushPartial`]: crate::FlushPartial
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tryiter::TryIteratorExt;
+    ///
+    /// let records = vec![Ok(1), Ok(2), Ok(3), Ok(4), Ok(5), Err("error"), Ok(6)];
+    /// let batches: Vec<_> = records.into_iter().try_chunks(2).collect();
+    /// assert_eq!(
+    ///     batches,
+    ///     vec![Ok(vec![1, 2]), Ok(vec![3, 4]), Ok(vec![5]), Err("error"), Ok(vec![6])]
+    /// );
+    /// ```
+    #[cfg(feature = "alloc")]
+    fn try_chunks(self, size: usize) -> TryChunks<Self>
+    where
+        Self: Sized,
+    {
+        TryChunks::new(self, size)
+    }
+
+    /// Groups the success values into arrays of `N` elements, passing errors
+    /// through. Elements left over at the end are available through
+    /// [`TryArrayChunks::remainder`].
+    ///
+    /// By default a partial chunk interrupted by an error is dropped
+    /// ([`DiscardPartial`]). Use [`TryArrayChunks::attach_partial`] to
+    /// retrieve it along with the error.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `N` is 0.
+    ///
+    /// [`DiscardPartial`]: crate::DiscardPartial
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tryiter::TryIteratorExt;
+    ///
+    /// let mut pairs = vec![Ok(1), Ok(2), Ok(3), Err("error"), Ok(4), Ok(5), Ok(6)]
+    ///     .into_iter()
+    ///     .try_array_chunks::<2>();
+    ///
+    /// assert_eq!(pairs.next(), Some(Ok([1, 2])));
+    /// assert_eq!(pairs.next(), Some(Err("error")));
+    /// assert_eq!(pairs.next(), Some(Ok([4, 5])));
+    /// assert_eq!(pairs.next(), None);
+    /// assert_eq!(pairs.remainder(), &[6]);
+    /// ```
+    #[cfg(feature = "alloc")]
+    fn try_array_chunks<const N: usize>(self) -> TryArrayChunks<Self, N>
+    where
+        Self: Sized,
+    {
+        TryArrayChunks::new(self)
+    }
+
+    /// Creates an iterator over overlapping windows of `size` consecutive
+    /// success values. Errors are passed through without affecting the
+    /// window, unless [`TryWindows::reset_on_err`] is used.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `size` is 0.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tryiter::TryIteratorExt;
+    ///
+    /// let readings = vec![Ok(10), Ok(12), Err("sensor offline"), Ok(11)];
+    /// let windows: Vec<_> = readings.into_iter().try_windows(2).collect();
+    /// assert_eq!(
+    ///     windows,
+    ///     vec![Ok(vec![10, 12]), Err("sensor offline"), Ok(vec![12, 11])]
+    /// );
+    /// ```
+    #[cfg(feature = "alloc")]
+    fn try_windows(self, size: usize) -> TryWindows<Self>
+    where
+        Self: Sized,
+    {
+        TryWindows::new(self, size)
+    }
+
+    /// Creates an iterator over overlapping windows of consecutive success
+    /// values as tuples, such as `(T, T)` for pairs. Errors are passed
+    /// through without affecting the window, unless
+    /// [`TryTupleWindows::reset_on_err`] is used.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tryiter::TryIteratorExt;
+    ///
+    /// let readings = vec![Ok(10), Ok(12), Err("sensor offline"), Ok(11)];
+    /// let deltas: Vec<_> = readings
+    ///     .into_iter()
+    ///     .try_tuple_windows::<(i32, i32)>()
+    ///     .map_ok(|(a, b)| Ok(b - a))
+    ///     .collect();
+    /// assert_eq!(deltas, vec![Ok(2), Err("sensor offline"), Ok(-1)]);
+    /// ```
+    #[cfg(feature = "alloc")]
+    fn try_tuple_windows<T>(self) -> TryTupleWindows<Self, T>
+    where
+        Self: Sized,
+        T: TupleWindow<Item = Self::Ok>,
+    {
+        TryTupleWindows::new(self)
     }
 
     /// Returns `true` if the provided closure returns `true` for all success
@@ -233,9 +565,72 @@ pub trait TryIteratorExt: TryIterator {
         Ok(false)
     }
 
+    /// Searches for a success value from the back of the iterator that
+    /// satisfies the provided predicate. Errors are passed through.
+    ///
+    /// This method is short-circuiting; it will stop processing as soon as the
+    /// predicate returns `true` or an error is encountered.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tryiter::TryIteratorExt;
+    ///
+    /// let mut iter = vec![Ok(1), Ok(2), Ok(3), Ok(4)].into_iter();
+    /// assert_eq!(iter.try_rfind(|x| Ok::<_, &str>(x % 2 == 1)), Ok(Some(3)));
+    ///
+    /// // The iterator stopped before consuming all elements
+    /// assert_eq!(iter.next(), Some(Ok(1)));
+    ///
+    /// let mut iter = vec![Ok(1), Err("error"), Ok(2)].into_iter();
+    /// assert_eq!(iter.try_rfind(|x| Ok(*x == 1)), Err("error"));
+    /// ```
+    fn try_rfind<P>(&mut self, mut predicate: P) -> Result<Option<Self::Ok>, Self::Err>
+    where
+        Self: Sized + DoubleEndedIterator,
+        P: FnMut(&Self::Ok) -> Result<bool, Self::Err>,
+    {
+        while let Some(result) = self.next_back() {
+            let value = result?;
+            if predicate(&value)? {
+                return Ok(Some(value));
+            }
+        }
+        Ok(None)
+    }
+
+    /// Folds every success value into an accumulator starting from the back
+    /// of the iterator, returning the first error encountered either from the
+    /// iterator or the provided closure.
+    ///
+    /// This is the fallible counterpart of [`DoubleEndedIterator::rfold`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tryiter::TryIteratorExt;
+    ///
+    /// let iter = vec![Ok(1), Ok(2), Ok(3)].into_iter();
+    /// let digits = iter.rfold_ok(0, |acc, x| Ok::<_, &str>(acc * 10 + x));
+    /// assert_eq!(digits, Ok(321));
+    ///
+    /// let iter = vec![Ok(1), Err("error"), Ok(3)].into_iter();
+    /// assert_eq!(iter.rfold_ok(0, |acc, x| Ok(acc + x)), Err("error"));
+    /// ```
+    fn rfold_ok<B, F>(mut self, init: B, mut f: F) -> Result<B, Self::Err>
+    where
+        Self: Sized + DoubleEndedIterator,
+        F: FnMut(B, Self::Ok) -> Result<B, Self::Err>,
+    {
+        self.try_rfold(init, |acc, result| f(acc, result?))
+    }
+
     /// Wraps the current iterator in a new iterator that allows peeking at the
     /// next element without consuming it.
     ///
+    /// By default errors encountered while peeking are returned and not
+    /// stored, see [`TryPeekable::stash_errors`] to retain them instead.
+    ///
     /// # Examples
     ///
     /// ```
@@ -269,7 +664,68 @@ pub trait TryIteratorExt: TryIterator {
         TryPeekable::new(self)
     }
 
-    /// This is basically the fallible version of [`std::iter::Iterator::unzip`]
+    /// Wraps the current iterator in a new iterator that allows peeking at
+    /// any number of elements ahead without consuming them.
+    ///
+    /// By default errors encountered while peeking are returned and not
+    /// stored, see [`TryMultiPeek::stash_errors`] to buffer them instead.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tryiter::TryIteratorExt;
+    ///
+    /// let mut iter = vec![Ok('a'), Ok('='), Ok('b')].into_iter().try_multipeek();
+    ///
+    /// // look two elements ahead to decide how to parse the first one
+    /// let is_assignment = iter.try_peek_nth(1)? == Some(&'=');
+    /// assert!(is_assignment);
+    ///
+    /// assert_eq!(iter.try_next()?, Some('a'));
+    /// assert_eq!(iter.try_peek()?, Some(&'='));
+    /// assert_eq!(iter.try_peek()?, Some(&'b'));
+    /// assert_eq!(iter.try_peek()?, None);
+    /// # Ok::<(), &str>(())
+    /// ```
+    #[cfg(feature = "alloc")]
+    fn try_multipeek(self) -> TryMultiPeek<Self>
+    where
+        Self: Sized,
+    {
+        TryMultiPeek::new(self)
+    }
+
+    /// Wraps the current iterator in a new iterator that allows Ok values to
+    /// be put back, see [`TryPutBack`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tryiter::TryIteratorExt;
+    ///
+    /// let mut iter = vec![Ok("let"), Ok("x"), Err("error")]
+    ///     .into_iter()
+    ///     .try_put_back();
+    ///
+    /// let keyword = iter.try_next()?.unwrap();
+    /// if keyword != "fn" {
+    ///     iter.put_back(keyword);
+    /// }
+    ///
+    /// assert_eq!(iter.try_next(), Ok(Some("let")));
+    /// assert_eq!(iter.try_next(), Ok(Some("x")));
+    /// assert_eq!(iter.try_next(), Err("error"));
+    /// # Ok::<(), &str>(())
+    /// ```
+    #[cfg(feature = "alloc")]
+    fn try_put_back(self) -> TryPutBack<Self>
+    where
+        Self: Sized,
+    {
+        TryPutBack::new(self)
+    }
+
+    /// This is basically the fallible version of [`Iterator::unzip`]
     ///
     /// Converts an iterator of [`Result`] of pairs into a [`Result`] of pair of containers.
     ///
@@ -306,34 +762,347 @@ pub trait TryIteratorExt: TryIterator {
             (FromA::default(), FromB::default()),
             |(mut left_list, mut right_list), couple| {
                 let (l, r) = couple?;
-                left_list.extend(std::iter::once(l));
-                right_list.extend(std::iter::once(r));
+                left_list.extend(core::iter::once(l));
+                right_list.extend(core::iter::once(r));
                 Ok((left_list, right_list))
             },
         )
     }
 
-    /// Fallible version of [`Iterator::max`]
-    /// If every element is a [`Result::Ok`], it has the same behavior.
-    ///
-    /// - It returns the maximum element of the iterator.
-    /// - If several elements are equally maximum, the last element is returned.
-    /// - If the iterator is empty, [`Option::None`] is returned.
+    /// Calls `f` with an iterator over the success values which stops at the
+    /// first error, then returns that error or the output of `f`.
     ///
-    /// Otherwise, returns the first error encountered.
-    ///
-    /// Note: This differs in calling [`Iterator::max`] which would not stop at
-    /// the first error but would return the maximal [`Result::Err`] if several
-    /// errors are encountered.
+    /// This allows any consumer of an `Iterator<Item = T>` to be driven by a
+    /// fallible iterator, like `collect::<Result<_, _>>()` does for
+    /// [`FromIterator`]. See [`ErrorSlot`] to manage the error yourself.
     ///
     /// # Examples
     ///
     /// ```
     /// use tryiter::TryIteratorExt;
     ///
-    /// let v = [Ok(5), Ok(3), Ok(9), Ok(7), Ok(2)];
-    /// let max: Result<_, i32> = v.into_iter().try_max();
-    /// assert_eq!(max, Ok(Some(9)));
+    /// let mut lines = vec![];
+    /// let written = vec![Ok("a"), Ok("b")]
+    ///     .into_iter()
+    ///     .shunt(|iter| lines.extend(iter));
+    /// assert_eq!(written, Ok::<(), &str>(()));
+    ///
+    /// let written = vec![Ok("c"), Err("disk full"), Ok("d")]
+    ///     .into_iter()
+    ///     .shunt(|iter| lines.extend(iter));
+    /// assert_eq!(written, Err("disk full"));
+    /// assert_eq!(lines, vec!["a", "b", "c"]);
+    /// ```
+    fn shunt<R, F>(self, f: F) -> Result<R, Self::Err>
+    where
+        Self: Sized,
+        F: FnOnce(Shunt<'_, Self>) -> R,
+    {
+        let mut slot = ErrorSlot::new();
+        let value = f(slot.shunt(self));
+        slot.into_result(value)
+    }
+
+    /// Consumes the whole iterator, collecting either every success value or
+    /// every error.
+    ///
+    /// Unlike collecting into a [`Result`], this method does not stop at the
+    /// first error. If no errors are encountered, the success values are
+    /// collected into `C`. Otherwise, each error is wrapped in an
+    /// [`IndexedError`] recording the position of the element which produced
+    /// it, and all of them are collected into `Errs`, which can be any
+    /// `Default + Extend` container or a custom [`ErrorCollector`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tryiter::{IndexedError, TryIteratorExt};
+    ///
+    /// let valid = vec![Ok(1), Ok(2), Ok(3)].into_iter();
+    /// let collected: Result<Vec<_>, Vec<IndexedError<&str>>> = valid.try_collect_all_errors();
+    /// assert_eq!(collected, Ok(vec![1, 2, 3]));
+    ///
+    /// let invalid = vec![Ok(1), Err("bad"), Ok(3), Err("worse")].into_iter();
+    /// let errors = invalid.try_collect_all_errors::<Vec<_>, Vec<_>>().unwrap_err();
+    /// assert_eq!(errors, vec![IndexedError::new(1, "bad"), IndexedError::new(3, "worse")]);
+    /// ```
+    fn try_collect_all_errors<C, Errs>(self) -> Result<C, Errs>
+    where
+        Self: Sized,
+        C: FromIterator<Self::Ok>,
+        Errs: ErrorCollector<IndexedError<Self::Err>>,
+    {
+        collect_errors::collect_all_errors(self)
+    }
+
+    /// Consumes the iterator, splitting it into two containers: one with all
+    /// the success values and one with all the errors.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tryiter::TryIteratorExt;
+    ///
+    /// let iter = vec![Ok(1), Err("a"), Ok(2), Err("b")].into_iter();
+    /// let (oks, errs): (Vec<_>, Vec<_>) = iter.partition_results();
+    ///
+    /// assert_eq!(oks, vec![1, 2]);
+    /// assert_eq!(errs, vec!["a", "b"]);
+    /// ```
+    fn partition_results<OkC, ErrC>(self) -> (OkC, ErrC)
+    where
+        Self: Sized,
+        OkC: Default + Extend<Self::Ok>,
+        ErrC: Default + Extend<Self::Err>,
+    {
+        self.fold(
+            (OkC::default(), ErrC::default()),
+            |(mut oks, mut errs), result| {
+                match result {
+                    Ok(ok) => oks.extend(core::iter::once(ok)),
+                    Err(err) => errs.extend(core::iter::once(err)),
+                }
+                (oks, errs)
+            },
+        )
+    }
+
+    /// Lazily splits the iterator into two linked iterators: one yielding the
+    /// success values and one yielding the errors.
+    ///
+    /// Both iterators pull from the same underlying iterator. Values of the
+    /// other kind encountered along the way are buffered until the other
+    /// iterator consumes them, so consuming both iterators in step requires
+    /// little memory.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tryiter::TryIteratorExt;
+    ///
+    /// let iter = vec![Ok(1), Err("a"), Ok(2), Err("b"), Ok(3)].into_iter();
+    /// let (mut oks, mut errs) = iter.partition_results_lazy();
+    ///
+    /// assert_eq!(oks.next(), Some(1));
+    /// assert_eq!(oks.next(), Some(2));
+    /// assert_eq!(errs.next(), Some("a"));
+    /// assert_eq!(errs.next(), Some("b"));
+    /// assert_eq!(errs.next(), None);
+    ///
+    /// // the success value encountered while searching for errors is buffered
+    /// assert_eq!(oks.next(), Some(3));
+    /// assert_eq!(oks.next(), None);
+    /// ```
+    #[cfg(feature = "alloc")]
+    fn partition_results_lazy(self) -> (PartitionOk<Self>, PartitionErr<Self>)
+    where
+        Self: Sized,
+    {
+        partition::new(self)
+    }
+
+    /// Collects the success values of the iterator and sorts them, stopping
+    /// at the first error.
+    ///
+    /// The sort is stable, and is equivalent to collecting into a
+    /// `Result<Vec<_>, _>` before calling [`slice::sort`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tryiter::TryIteratorExt;
+    ///
+    /// let sorted = vec![Ok::<_, &str>(3), Ok(1), Ok(2)].into_iter().try_sorted();
+    /// assert_eq!(sorted.map(Iterator::collect), Ok(vec![1, 2, 3]));
+    ///
+    /// let sorted = vec![Ok(3), Err("error"), Ok(2)].into_iter().try_sorted();
+    /// assert_eq!(sorted.map(Iterator::collect::<Vec<_>>), Err("error"));
+    /// ```
+    #[cfg(feature = "alloc")]
+    fn try_sorted(self) -> Result<vec::IntoIter<Self::Ok>, Self::Err>
+    where
+        Self: Sized,
+        Self::Ok: Ord,
+    {
+        let mut sorted = self.collect::<Result<Vec<_>, _>>()?;
+        sorted.sort();
+        Ok(sorted.into_iter())
+    }
+
+    /// Collects the success values of the iterator and sorts them with the
+    /// specified fallible comparison function.
+    ///
+    /// The sort is a stable merge sort, which stops at the first error
+    /// yielded by the iterator or returned by the comparison function.
+    /// Elements are sorted as they are collected, so a comparison error may
+    /// be returned before the iterator is exhausted. The comparison function
+    /// is never called again after it fails.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tryiter::TryIteratorExt;
+    ///
+    /// let by_version = |a: &&str, b: &&str| -> Result<_, String> {
+    ///     let a: u32 = a.parse().map_err(|_| format!("bad version {a}"))?;
+    ///     let b: u32 = b.parse().map_err(|_| format!("bad version {b}"))?;
+    ///     Ok(a.cmp(&b))
+    /// };
+    ///
+    /// let sorted = vec![Ok("10"), Ok("9"), Ok("100")]
+    ///     .into_iter()
+    ///     .try_sorted_by(by_version);
+    /// assert_eq!(sorted.map(Iterator::collect), Ok(vec!["9", "10", "100"]));
+    ///
+    /// let sorted = vec![Ok("10"), Ok("nine"), Ok("100")]
+    ///     .into_iter()
+    ///     .try_sorted_by(by_version);
+    /// assert_eq!(
+    ///     sorted.map(Iterator::collect::<Vec<_>>),
+    ///     Err("bad version nine".to_string())
+    /// );
+    /// ```
+    #[cfg(feature = "alloc")]
+    fn try_sorted_by<F>(self, mut compare: F) -> Result<vec::IntoIter<Self::Ok>, Self::Err>
+    where
+        Self: Sized,
+        F: FnMut(&Self::Ok, &Self::Ok) -> Result<core::cmp::Ordering, Self::Err>,
+    {
+        Ok(sorted::try_sort_by(self, &mut compare)?.into_iter())
+    }
+
+    /// Collects the success values of the iterator and sorts them by the
+    /// key returned by the specified fallible function, stopping at the first
+    /// error of the iterator or of the function.
+    ///
+    /// The sort is stable, and the function is called once per element, as
+    /// with [`slice::sort_by_cached_key`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tryiter::TryIteratorExt;
+    ///
+    /// let sorted = vec![Ok::<_, ()>("ccc"), Ok("a"), Ok("bb"), Ok("d")]
+    ///     .into_iter()
+    ///     .try_sorted_by_key(|s| Ok(s.len()));
+    /// assert_eq!(sorted.map(Iterator::collect), Ok(vec!["a", "d", "bb", "ccc"]));
+    ///
+    /// let sorted = vec![Ok("10"), Ok("nine"), Ok("100")]
+    ///     .into_iter()
+    ///     .try_sorted_by_key(|s| s.parse::<u32>().map_err(|_| format!("bad version {s}")));
+    /// assert_eq!(
+    ///     sorted.map(Iterator::collect::<Vec<_>>),
+    ///     Err("bad version nine".to_string())
+    /// );
+    /// ```
+    #[cfg(feature = "alloc")]
+    fn try_sorted_by_key<K, F>(self, mut f: F) -> Result<vec::IntoIter<Self::Ok>, Self::Err>
+    where
+        Self: Sized,
+        K: Ord,
+        F: FnMut(&Self::Ok) -> Result<K, Self::Err>,
+    {
+        let mut keyed = self
+            .map_ok(|x| Ok((f(&x)?, x)))
+            .collect::<Result<Vec<_>, _>>()?;
+        keyed.sort_by(|(a, _), (b, _)| a.cmp(b));
+        let sorted: Vec<_> = keyed.into_iter().map(|(_, x)| x).collect();
+        Ok(sorted.into_iter())
+    }
+
+    /// Sorts the success values of the iterator with the specified fallible
+    /// comparison function, spilling sorted runs to temporary files so that
+    /// inputs larger than memory can be sorted.
+    ///
+    /// The iterator is consumed in runs of [`ExternalSort::run_len`]
+    /// elements, each sorted in memory as with [`try_sorted_by`] and written
+    /// to a temporary file with the configured [`SortCodec`]. The returned
+    /// iterator merges the runs lazily, reading one element of each run at a
+    /// time. The last run is kept in memory, so an input shorter than a run
+    /// is never written to disk.
+    ///
+    /// At most [`ExternalSort::fan_in`] runs are merged at once. When there
+    /// are more, groups of runs are first merged into intermediate temporary
+    /// files, in as many passes as needed, so the number of open files stays
+    /// bounded.
+    ///
+    /// The sort is stable. An error of the iterator or of the comparison
+    /// function while runs are built is returned as
+    /// [`ExternalSortError::Source`]; the temporary files are removed once
+    /// they are dropped. Errors while merging are yielded by the returned
+    /// iterator, which then stops.
+    ///
+    /// [`try_sorted_by`]: TryIteratorExt::try_sorted_by
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::io::{self, BufRead, Write};
+    /// use tryiter::{ExternalSort, SortCodec, TryIteratorExt};
+    ///
+    /// struct Lines;
+    ///
+    /// impl SortCodec<u64> for Lines {
+    ///     type Error = io::Error;
+    ///
+    ///     fn encode(&mut self, value: &u64, writer: &mut dyn Write) -> io::Result<()> {
+    ///         writeln!(writer, "{value}")
+    ///     }
+    ///
+    ///     fn decode(&mut self, reader: &mut dyn BufRead) -> io::Result<Option<u64>> {
+    ///         let mut line = String::new();
+    ///         if reader.read_line(&mut line)? == 0 {
+    ///             return Ok(None);
+    ///         }
+    ///         let value = line.trim_end().parse();
+    ///         value.map(Some).map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))
+    ///     }
+    /// }
+    ///
+    /// let input = (0..10_000u64).rev().map(Ok::<_, io::Error>);
+    /// let config = ExternalSort::new(Lines).run_len(1_000);
+    /// let sorted = input.try_external_sorted_by(config, |a, b| Ok(a.cmp(b))).unwrap();
+    ///
+    /// let sorted: Vec<_> = sorted.collect::<Result<_, _>>().unwrap();
+    /// assert_eq!(sorted, (0..10_000).collect::<Vec<_>>());
+    /// ```
+    #[cfg(feature = "external-sort")]
+    #[allow(clippy::type_complexity)]
+    fn try_external_sorted_by<C, F>(
+        self,
+        config: ExternalSort<C>,
+        compare: F,
+    ) -> Result<ExternalSorted<Self::Ok, Self::Err, C, F>, ExternalSortError<Self::Err, C::Error>>
+    where
+        Self: Sized,
+        C: SortCodec<Self::Ok>,
+        F: FnMut(&Self::Ok, &Self::Ok) -> Result<core::cmp::Ordering, Self::Err>,
+    {
+        ExternalSorted::new(self, config, compare)
+    }
+
+    /// Fallible version of [`Iterator::max`]
+    /// If every element is a [`Result::Ok`], it has the same behavior.
+    ///
+    /// - It returns the maximum element of the iterator.
+    /// - If several elements are equally maximum, the last element is returned.
+    /// - If the iterator is empty, [`Option::None`] is returned.
+    ///
+    /// Otherwise, returns the first error encountered.
+    ///
+    /// Note: This differs in calling [`Iterator::max`] which would not stop at
+    /// the first error but would return the maximal [`Result::Err`] if several
+    /// errors are encountered.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tryiter::TryIteratorExt;
+    ///
+    /// let v = [Ok(5), Ok(3), Ok(9), Ok(7), Ok(2)];
+    /// let max: Result<_, i32> = v.into_iter().try_max();
+    /// assert_eq!(max, Ok(Some(9)));
     ///                                                    
     /// let v = [Ok(5), Err(3), Err(9), Ok(7), Ok(2)];
     /// let max = v.into_iter().try_max();
@@ -373,13 +1142,13 @@ pub trait TryIteratorExt: TryIterator {
     fn try_max_by<F>(mut self, mut compare: F) -> Result<Option<Self::Ok>, Self::Err>
     where
         Self: Sized + TryIterator,
-        F: FnMut(&Self::Ok, &Self::Ok) -> std::cmp::Ordering,
+        F: FnMut(&Self::Ok, &Self::Ok) -> core::cmp::Ordering,
     {
         match self.next() {
             None => Ok(None),
             Some(Err(e)) => Err(e),
             Some(Ok(v)) => Some(self.try_fold(v, |acc, x| match x {
-                Ok(x) => Ok(std::cmp::max_by(acc, x, &mut compare)),
+                Ok(x) => Ok(core::cmp::max_by(acc, x, &mut compare)),
                 Err(e) => Err(e),
             }))
             .transpose(),
@@ -418,7 +1187,7 @@ pub trait TryIteratorExt: TryIterator {
             None => Ok(None),
             Some(Err(e)) => Err(e),
             Some(Ok(v)) => Some(self.try_fold(v, |acc, x| match x {
-                Ok(x) => Ok(std::cmp::max_by_key(acc, x, &mut f)),
+                Ok(x) => Ok(core::cmp::max_by_key(acc, x, &mut f)),
                 Err(e) => Err(e),
             }))
             .transpose(),
@@ -429,7 +1198,7 @@ pub trait TryIteratorExt: TryIterator {
     /// If every element is a [`Result::Ok`], it has the same behavior.
     ///
     /// - It returns the minimum element of the iterator.
-    /// - If several elements are equally minimum, the last element is returned.
+    /// - If several elements are equally minimum, the first element is returned.
     /// - If the iterator is empty, [`Option::None`] is returned.
     ///
     /// Otherwise, returns the first error encountered.
@@ -464,7 +1233,7 @@ pub trait TryIteratorExt: TryIterator {
     ///
     /// - Returns the element that gives the minimum value with respect to the
     ///   specified comparison function.
-    /// - If several elements are equally minimum, the last element is returned.
+    /// - If several elements are equally minimum, the first element is returned.
     /// - If the iterator is empty, [`Option::None`] is returned.
     ///
     /// Otherwise, returns the first error encountered.
@@ -485,13 +1254,13 @@ pub trait TryIteratorExt: TryIterator {
     fn try_min_by<F>(mut self, mut compare: F) -> Result<Option<Self::Ok>, Self::Err>
     where
         Self: Sized + TryIterator,
-        F: FnMut(&Self::Ok, &Self::Ok) -> std::cmp::Ordering,
+        F: FnMut(&Self::Ok, &Self::Ok) -> core::cmp::Ordering,
     {
         match self.next() {
             None => Ok(None),
             Some(Err(e)) => Err(e),
             Some(Ok(v)) => Some(self.try_fold(v, |acc, x| match x {
-                Ok(x) => Ok(std::cmp::min_by(acc, x, &mut compare)),
+                Ok(x) => Ok(core::cmp::min_by(acc, x, &mut compare)),
                 Err(e) => Err(e),
             }))
             .transpose(),
@@ -502,7 +1271,7 @@ pub trait TryIteratorExt: TryIterator {
     /// If every element is a [`Result::Ok`], it has the same behavior.
     ///
     /// - Returns the element that gives the minimum value from the specified function.
-    /// - If several elements are equally minimum, the last element is returned.
+    /// - If several elements are equally minimum, the first element is returned.
     /// - If the iterator is empty, [`Option::None`] is returned.
     ///
     /// Otherwise, returns the first error encountered.
@@ -530,12 +1299,469 @@ pub trait TryIteratorExt: TryIterator {
             None => Ok(None),
             Some(Err(e)) => Err(e),
             Some(Ok(v)) => Some(self.try_fold(v, |acc, x| match x {
-                Ok(x) => Ok(std::cmp::min_by_key(acc, x, &mut f)),
+                Ok(x) => Ok(core::cmp::min_by_key(acc, x, &mut f)),
                 Err(e) => Err(e),
             }))
             .transpose(),
         }
     }
+
+    /// Returns the maximum element of the iterator with respect to the
+    /// specified fallible comparison function.
+    ///
+    /// If several elements are equally maximum, the last element is
+    /// returned. Returns the first error, either yielded by the iterator or
+    /// returned by the comparison function.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::collections::HashMap;
+    /// use tryiter::TryIteratorExt;
+    ///
+    /// let rank = HashMap::from([("low", 0), ("high", 2), ("medium", 1)]);
+    /// let compare = |a: &&str, b: &&str| match (rank.get(a), rank.get(b)) {
+    ///     (Some(a), Some(b)) => Ok(a.cmp(b)),
+    ///     _ => Err("unknown priority"),
+    /// };
+    ///
+    /// let v = [Ok("low"), Ok("high"), Ok("medium")];
+    /// assert_eq!(v.into_iter().try_max_by_fallible(compare), Ok(Some("high")));
+    ///
+    /// let v = [Ok("low"), Ok("urgent"), Ok("medium")];
+    /// assert_eq!(v.into_iter().try_max_by_fallible(compare), Err("unknown priority"));
+    /// ```
+    fn try_max_by_fallible<F>(mut self, mut compare: F) -> Result<Option<Self::Ok>, Self::Err>
+    where
+        Self: Sized,
+        F: FnMut(&Self::Ok, &Self::Ok) -> Result<core::cmp::Ordering, Self::Err>,
+    {
+        let first = match self.next() {
+            None => return Ok(None),
+            Some(x) => x?,
+        };
+        self.try_fold(first, |acc, x| {
+            let x = x?;
+            Ok(match compare(&acc, &x)? {
+                core::cmp::Ordering::Greater => acc,
+                _ => x,
+            })
+        })
+        .map(Some)
+    }
+
+    /// Returns the element that gives the maximum value from the specified
+    /// fallible function, which is called once per element.
+    ///
+    /// If several elements are equally maximum, the last element is
+    /// returned. Returns the first error, either yielded by the iterator or
+    /// returned by the function.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tryiter::TryIteratorExt;
+    ///
+    /// let v = [Ok("3"), Ok("12"), Ok("7")];
+    /// let max = v.into_iter().try_max_by_key_fallible(|s| s.parse::<u32>());
+    /// assert_eq!(max, Ok(Some("12")));
+    ///
+    /// let v = [Ok("3"), Ok("twelve"), Ok("7")];
+    /// let max = v.into_iter().try_max_by_key_fallible(|s| s.parse::<u32>());
+    /// assert!(max.is_err());
+    /// ```
+    fn try_max_by_key_fallible<B, F>(mut self, mut f: F) -> Result<Option<Self::Ok>, Self::Err>
+    where
+        Self: Sized,
+        B: Ord,
+        F: FnMut(&Self::Ok) -> Result<B, Self::Err>,
+    {
+        let first = match self.next() {
+            None => return Ok(None),
+            Some(x) => x?,
+        };
+        let key = f(&first)?;
+        self.try_fold((key, first), |acc, x| {
+            let x = x?;
+            let key = f(&x)?;
+            Ok(if acc.0 > key { acc } else { (key, x) })
+        })
+        .map(|(_, max)| Some(max))
+    }
+
+    /// Returns the minimum element of the iterator with respect to the
+    /// specified fallible comparison function.
+    ///
+    /// If several elements are equally minimum, the first element is
+    /// returned. Returns the first error, either yielded by the iterator or
+    /// returned by the comparison function.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tryiter::TryIteratorExt;
+    ///
+    /// let compare = |a: &u32, b: &u32| {
+    ///     let a = a.checked_mul(1000).ok_or("overflow")?;
+    ///     let b = b.checked_mul(1000).ok_or("overflow")?;
+    ///     Ok(a.cmp(&b))
+    /// };
+    ///
+    /// let v = [Ok(5), Ok(3), Ok(9)];
+    /// assert_eq!(v.into_iter().try_min_by_fallible(compare), Ok(Some(3)));
+    ///
+    /// let v = [Ok(5), Ok(u32::MAX), Ok(9)];
+    /// assert_eq!(v.into_iter().try_min_by_fallible(compare), Err("overflow"));
+    /// ```
+    fn try_min_by_fallible<F>(mut self, mut compare: F) -> Result<Option<Self::Ok>, Self::Err>
+    where
+        Self: Sized,
+        F: FnMut(&Self::Ok, &Self::Ok) -> Result<core::cmp::Ordering, Self::Err>,
+    {
+        let first = match self.next() {
+            None => return Ok(None),
+            Some(x) => x?,
+        };
+        self.try_fold(first, |acc, x| {
+            let x = x?;
+            Ok(match compare(&acc, &x)? {
+                core::cmp::Ordering::Greater => x,
+                _ => acc,
+            })
+        })
+        .map(Some)
+    }
+
+    /// Returns the element that gives the minimum value from the specified
+    /// fallible function, which is called once per element.
+    ///
+    /// If several elements are equally minimum, the first element is
+    /// returned. Returns the first error, either yielded by the iterator or
+    /// returned by the function.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tryiter::TryIteratorExt;
+    ///
+    /// let v = [Ok("3"), Ok("12"), Ok("7")];
+    /// let min = v.into_iter().try_min_by_key_fallible(|s| s.parse::<u32>());
+    /// assert_eq!(min, Ok(Some("3")));
+    /// ```
+    fn try_min_by_key_fallible<B, F>(mut self, mut f: F) -> Result<Option<Self::Ok>, Self::Err>
+    where
+        Self: Sized,
+        B: Ord,
+        F: FnMut(&Self::Ok) -> Result<B, Self::Err>,
+    {
+        let first = match self.next() {
+            None => return Ok(None),
+            Some(x) => x?,
+        };
+        let key = f(&first)?;
+        self.try_fold((key, first), |acc, x| {
+            let x = x?;
+            let key = f(&x)?;
+            Ok(if key < acc.0 { (key, x) } else { acc })
+        })
+        .map(|(_, min)| Some(min))
+    }
+
+    /// Returns the maximum element of an iterator over partially ordered
+    /// values, such as floats.
+    ///
+    /// If several elements are equally maximum, the last element is
+    /// returned. Stops at the first error of the iterator, returned as
+    /// [`ReduceError::Source`], or at the first pair of values which can not
+    /// be compared, returned as [`ReduceError::Incomparable`]. A value which
+    /// can not be compared with itself, such as NaN, is rejected even if it
+    /// is the only element.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tryiter::{ReduceError, TryIteratorExt};
+    ///
+    /// let v = [Ok(1.5), Ok(-2.0), Ok(4.25)];
+    /// let max: Result<_, ReduceError<()>> = v.into_iter().try_max_partial();
+    /// assert_eq!(max, Ok(Some(4.25)));
+    ///
+    /// let v = [Ok(1.5), Ok(f64::NAN), Ok(4.25)];
+    /// let max: Result<_, ReduceError<()>> = v.into_iter().try_max_partial();
+    /// assert_eq!(max, Err(ReduceError::Incomparable));
+    /// ```
+    fn try_max_partial(self) -> Result<Option<Self::Ok>, ReduceError<Self::Err>>
+    where
+        Self: Sized,
+        Self::Ok: PartialOrd,
+    {
+        self.map_err(ReduceError::Source)
+            .try_max_by_fallible(|a, b| a.partial_cmp(b).ok_or(ReduceError::Incomparable))
+            .and_then(reduce::self_comparable)
+    }
+
+    /// Returns the minimum element of an iterator over partially ordered
+    /// values, such as floats.
+    ///
+    /// If several elements are equally minimum, the first element is
+    /// returned. Stops at the first error of the iterator, returned as
+    /// [`ReduceError::Source`], or at the first pair of values which can not
+    /// be compared, returned as [`ReduceError::Incomparable`]. A value which
+    /// can not be compared with itself, such as NaN, is rejected even if it
+    /// is the only element.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tryiter::{ReduceError, TryIteratorExt};
+    ///
+    /// let v = [Ok(1.5), Ok(-2.0), Ok(4.25)];
+    /// let min: Result<_, ReduceError<()>> = v.into_iter().try_min_partial();
+    /// assert_eq!(min, Ok(Some(-2.0)));
+    ///
+    /// let v = [Ok(f64::NAN)];
+    /// let min: Result<_, ReduceError<()>> = v.into_iter().try_min_partial();
+    /// assert_eq!(min, Err(ReduceError::Incomparable));
+    /// ```
+    fn try_min_partial(self) -> Result<Option<Self::Ok>, ReduceError<Self::Err>>
+    where
+        Self: Sized,
+        Self::Ok: PartialOrd,
+    {
+        self.map_err(ReduceError::Source)
+            .try_min_by_fallible(|a, b| a.partial_cmp(b).ok_or(ReduceError::Incomparable))
+            .and_then(reduce::self_comparable)
+    }
+
+    /// Returns both the minimum and the maximum element of the iterator in a
+    /// single pass, stopping at the first error.
+    ///
+    /// Ties are broken as in [`try_min`] and [`try_max`]: if several elements
+    /// are equally minimum the first one is returned, and if several elements
+    /// are equally maximum the last one is returned.
+    ///
+    /// [`try_min`]: TryIteratorExt::try_min
+    /// [`try_max`]: TryIteratorExt::try_max
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tryiter::{MinMaxResult, TryIteratorExt};
+    ///
+    /// let v = [Ok(5), Ok(3), Ok(9), Ok(7), Ok(2)];
+    /// let minmax: Result<_, i32> = v.into_iter().try_minmax();
+    /// assert_eq!(minmax, Ok(MinMaxResult::MinMax(2, 9)));
+    ///
+    /// let v: [Result<i32, i32>; 1] = [Ok(5)];
+    /// assert_eq!(v.into_iter().try_minmax(), Ok(MinMaxResult::OneElement(5)));
+    ///
+    /// let v = [Ok(5), Err(3), Err(9), Ok(7), Ok(2)];
+    /// assert_eq!(v.into_iter().try_minmax(), Err(3));
+    /// ```
+    fn try_minmax(self) -> Result<MinMaxResult<Self::Ok>, Self::Err>
+    where
+        Self: Sized,
+        Self::Ok: Ord,
+    {
+        self.try_minmax_by(Self::Ok::cmp)
+    }
+
+    /// Returns both the minimum and the maximum element of the iterator with
+    /// respect to the specified comparison function, in a single pass and
+    /// stopping at the first error.
+    ///
+    /// If several elements are equally minimum the first one is returned,
+    /// and if several elements are equally maximum the last one is returned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tryiter::{MinMaxResult, TryIteratorExt};
+    ///
+    /// let v = [Ok((5, 0)), Ok((9, 0)), Ok((5, 1)), Ok((9, 1)), Ok((8, 0))];
+    /// let minmax: Result<_, i32> = v.into_iter().try_minmax_by(|x, y| x.0.cmp(&y.0));
+    /// assert_eq!(minmax, Ok(MinMaxResult::MinMax((5, 0), (9, 1))));
+    /// ```
+    fn try_minmax_by<F>(mut self, mut compare: F) -> Result<MinMaxResult<Self::Ok>, Self::Err>
+    where
+        Self: Sized,
+        F: FnMut(&Self::Ok, &Self::Ok) -> core::cmp::Ordering,
+    {
+        use core::cmp::Ordering;
+
+        let first = match self.next() {
+            None => return Ok(MinMaxResult::NoElements),
+            Some(x) => x?,
+        };
+        let (min, max) = match self.next() {
+            None => return Ok(MinMaxResult::OneElement(first)),
+            Some(x) => {
+                let second = x?;
+                if compare(&second, &first) == Ordering::Less {
+                    (second, first)
+                } else {
+                    (first, second)
+                }
+            }
+        };
+        self.try_fold((min, max), |(min, max), x| {
+            let x = x?;
+            Ok(if compare(&x, &min) == Ordering::Less {
+                (x, max)
+            } else if compare(&x, &max) != Ordering::Less {
+                (min, x)
+            } else {
+                (min, max)
+            })
+        })
+        .map(|(min, max)| MinMaxResult::MinMax(min, max))
+    }
+
+    /// Returns both the elements that give the minimum and the maximum value
+    /// from the specified function, in a single pass and stopping at the
+    /// first error. The function is called once per element.
+    ///
+    /// If several elements are equally minimum the first one is returned,
+    /// and if several elements are equally maximum the last one is returned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tryiter::{MinMaxResult, TryIteratorExt};
+    ///
+    /// let v = [Ok("apple"), Ok("fig"), Ok("banana"), Ok("kiwi"), Ok("cherry")];
+    /// let minmax: Result<_, ()> = v.into_iter().try_minmax_by_key(|s| s.len());
+    /// assert_eq!(minmax, Ok(MinMaxResult::MinMax("fig", "cherry")));
+    /// ```
+    fn try_minmax_by_key<B, F>(self, mut f: F) -> Result<MinMaxResult<Self::Ok>, Self::Err>
+    where
+        Self: Sized,
+        B: Ord,
+        F: FnMut(&Self::Ok) -> B,
+    {
+        let keyed = self
+            .map_ok(|x| Ok((f(&x), x)))
+            .try_minmax_by(|(a, _), (b, _)| a.cmp(b))?;
+        Ok(match keyed {
+            MinMaxResult::NoElements => MinMaxResult::NoElements,
+            MinMaxResult::OneElement((_, x)) => MinMaxResult::OneElement(x),
+            MinMaxResult::MinMax((_, min), (_, max)) => MinMaxResult::MinMax(min, max),
+        })
+    }
+
+    /// Sums the success values of the iterator, checking for overflow.
+    ///
+    /// This is an alias of [`try_checked_sum`]. It mirrors the name of
+    /// [`Iterator::sum`], but unlike it never panics or wraps on overflow,
+    /// which is returned as [`ReduceError::Overflow`].
+    ///
+    /// [`try_checked_sum`]: TryIteratorExt::try_checked_sum
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tryiter::{ReduceError, TryIteratorExt};
+    ///
+    /// let sum = vec![Ok::<i32, &str>(1), Ok(2), Ok(3)].into_iter().try_sum();
+    /// assert_eq!(sum, Ok(6));
+    ///
+    /// let sum = vec![Ok::<i32, &str>(i32::MAX), Ok(1)].into_iter().try_sum();
+    /// assert_eq!(sum, Err(ReduceError::Overflow));
+    /// ```
+    fn try_sum(self) -> Result<Self::Ok, ReduceError<Self::Err>>
+    where
+        Self: Sized,
+        Self::Ok: CheckedNum,
+    {
+        self.try_checked_sum()
+    }
+
+    /// Multiplies the success values of the iterator, checking for overflow.
+    ///
+    /// This is an alias of [`try_checked_product`]. It mirrors the name of
+    /// [`Iterator::product`], but unlike it never panics or wraps on
+    /// overflow, which is returned as [`ReduceError::Overflow`].
+    ///
+    /// [`try_checked_product`]: TryIteratorExt::try_checked_product
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tryiter::{ReduceError, TryIteratorExt};
+    ///
+    /// let product = vec![Ok::<i32, &str>(2), Ok(3), Ok(4)].into_iter().try_product();
+    /// assert_eq!(product, Ok(24));
+    ///
+    /// let product = vec![Ok::<i32, &str>(i32::MAX), Ok(2)].into_iter().try_product();
+    /// assert_eq!(product, Err(ReduceError::Overflow));
+    /// ```
+    fn try_product(self) -> Result<Self::Ok, ReduceError<Self::Err>>
+    where
+        Self: Sized,
+        Self::Ok: CheckedNum,
+    {
+        self.try_checked_product()
+    }
+
+    /// Sums the success values of the iterator, checking for overflow.
+    ///
+    /// Stops at the first error, returned as [`ReduceError::Source`], or at
+    /// the first overflow, returned as [`ReduceError::Overflow`]. An empty
+    /// iterator sums to [`CheckedNum::zero`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tryiter::{ReduceError, TryIteratorExt};
+    ///
+    /// let sum = vec![Ok::<u8, &str>(100), Ok(100)].into_iter().try_checked_sum();
+    /// assert_eq!(sum, Ok(200));
+    ///
+    /// let sum = vec![Ok::<u8, &str>(200), Ok(100)].into_iter().try_checked_sum();
+    /// assert_eq!(sum, Err(ReduceError::Overflow));
+    ///
+    /// let sum = vec![Ok::<u8, &str>(1), Err("error")].into_iter().try_checked_sum();
+    /// assert_eq!(sum, Err(ReduceError::Source("error")));
+    /// ```
+    fn try_checked_sum(mut self) -> Result<Self::Ok, ReduceError<Self::Err>>
+    where
+        Self: Sized,
+        Self::Ok: CheckedNum,
+    {
+        self.try_fold(Self::Ok::zero(), |acc, x| {
+            let x = x.map_err(ReduceError::Source)?;
+            acc.checked_add(x).ok_or(ReduceError::Overflow)
+        })
+    }
+
+    /// Multiplies the success values of the iterator, checking for overflow.
+    ///
+    /// Stops at the first error, returned as [`ReduceError::Source`], or at
+    /// the first overflow, returned as [`ReduceError::Overflow`]. An empty
+    /// iterator multiplies to [`CheckedNum::one`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tryiter::{ReduceError, TryIteratorExt};
+    ///
+    /// let product = vec![Ok::<i8, &str>(-8), Ok(16)].into_iter().try_checked_product();
+    /// assert_eq!(product, Ok(-128));
+    ///
+    /// let product = vec![Ok::<i8, &str>(8), Ok(16)].into_iter().try_checked_product();
+    /// assert_eq!(product, Err(ReduceError::Overflow));
+    /// ```
+    fn try_checked_product(mut self) -> Result<Self::Ok, ReduceError<Self::Err>>
+    where
+        Self: Sized,
+        Self::Ok: CheckedNum,
+    {
+        self.try_fold(Self::Ok::one(), |acc, x| {
+            let x = x.map_err(ReduceError::Source)?;
+            acc.checked_mul(x).ok_or(ReduceError::Overflow)
+        })
+    }
+
     /// Do something with the success value of the TryIterator, afterwards passing it on.
     ///
     /// This is similar to the [`Iterator::inspect`] method where it allows easily inspecting the success value as it passes through the iterator, for example to debug what’s going on.
@@ -553,16 +1779,12 @@ pub trait TryIteratorExt: TryIterator {
     ///     .for_each(|_| {});
     /// assert_eq!(vec![10, 14, 4], evens);
     /// ```
-    fn inspect_ok<F>(self, mut f: F) -> impl TryIterator<Ok = Self::Ok, Err = Self::Err>
+    fn inspect_ok<F>(self, f: F) -> InspectOk<Self, F>
     where
         Self: Sized + TryIterator,
         F: FnMut(&Self::Ok),
     {
-        self.inspect(move |item| {
-            if let Ok(item) = item {
-                f(item)
-            }
-        })
+        InspectOk::new(self, f)
     }
 
     /// Do something with the error value of the TryIterator, afterwards passing it on.
@@ -585,15 +1807,201 @@ pub trait TryIteratorExt: TryIterator {
     ///     .for_each(|_| {});
     /// assert_eq!(vec![Error(3), Error(9)], errs);
     ///```
-    fn inspect_err<F>(self, mut f: F) -> impl TryIterator<Ok = Self::Ok, Err = Self::Err>
+    fn inspect_err<F>(self, f: F) -> InspectErr<Self, F>
     where
         Self: Sized + TryIterator,
         F: FnMut(&Self::Err),
     {
-        self.inspect(move |item| {
-            if let Err(err) = item {
-                f(err)
-            }
-        })
+        InspectErr::new(self, f)
+    }
+
+    /// Wraps the current iterator in a new iterator that drops errors which
+    /// are tolerated by the provided [`ErrorPolicy`], reporting each of them
+    /// to the `on_tolerated` closure.
+    ///
+    /// The first error which is not tolerated is yielded wrapped in an
+    /// [`ErrorBudgetExceeded`] along with the number of errors tolerated so
+    /// far and its position in the iterator, after which the iterator stops.
+    ///
+    /// Policies include [`MaxErrors`], `MaxErrorRate` (requires the `alloc`
+    /// feature) and any closure of the form `FnMut(&E) -> bool`.
+    ///
+    /// [`ErrorBudgetExceeded`]: crate::ErrorBudgetExceeded
+    /// [`MaxErrors`]: crate::MaxErrors
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tryiter::{MaxErrors, TryIteratorExt};
+    ///
+    /// let v = [Ok(1), Err("a"), Ok(2), Err("b"), Ok(3), Err("c"), Ok(4)];
+    /// let mut dropped = vec![];
+    /// let mut iter = v
+    ///     .into_iter()
+    ///     .tolerate_errors(MaxErrors::new(2), |err| dropped.push(*err));
+    ///
+    /// assert_eq!(iter.next(), Some(Ok(1)));
+    /// assert_eq!(iter.next(), Some(Ok(2)));
+    /// assert_eq!(iter.next(), Some(Ok(3)));
+    ///
+    /// let err = iter.next().unwrap().unwrap_err();
+    /// assert_eq!(err.error(), &"c");
+    /// assert_eq!(err.tolerated(), 2);
+    /// assert_eq!(err.position(), 5);
+    ///
+    /// // the iterator stops once the budget is exceeded
+    /// assert_eq!(iter.next(), None);
+    /// drop(iter);
+    /// assert_eq!(dropped, vec!["a", "b"]);
+    /// ```
+    ///
+    /// Tolerating errors with a predicate:
+    ///
+    /// ```
+    /// use tryiter::TryIteratorExt;
+    ///
+    /// let v = [Ok(1), Err("skip"), Ok(2), Err("fatal"), Ok(3)];
+    /// let iter = v
+    ///     .into_iter()
+    ///     .tolerate_errors(|err: &&str| *err == "skip", |_| {});
+    ///
+    /// let results: Vec<_> = iter.map(|r| r.map_err(|e| e.into_error())).collect();
+    /// assert_eq!(results, vec![Ok(1), Ok(2), Err("fatal")]);
+    /// ```
+    fn tolerate_errors<P, F>(self, policy: P, on_tolerated: F) -> TolerateErrors<Self, P, F>
+    where
+        Self: Sized,
+        P: ErrorPolicy<Self::Err>,
+        F: FnMut(&Self::Err),
+    {
+        TolerateErrors::new(self, policy, on_tolerated)
+    }
+
+    /// Wraps the current iterator in a new iterator that wraps each error in
+    /// an [`IndexedError`] recording the zero-based position of the element
+    /// which produced it.
+    ///
+    /// Positions count the elements yielded by the current iterator, so they
+    /// match the input records as long as no adapter before this one drops
+    /// or buffers elements. To trace errors produced after filtering back to
+    /// the input, use an [`IndexTracker`] instead, which records positions
+    /// at the source.
+    ///
+    /// [`IndexTracker`]: crate::IndexTracker
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tryiter::TryIteratorExt;
+    ///
+    /// let records = vec![Ok("1"), Ok("two"), Err("unreadable")];
+    /// let mut iter = records
+    ///     .into_iter()
+    ///     .map_ok(|s| s.parse::<i32>().map_err(|_| "invalid number"))
+    ///     .with_index_context();
+    ///
+    /// assert_eq!(iter.next(), Some(Ok(1)));
+    ///
+    /// let err = iter.next().unwrap().unwrap_err();
+    /// assert_eq!(err.index(), 1);
+    /// assert_eq!(err.to_string(), "element 1: invalid number");
+    ///
+    /// let err = iter.next().unwrap().unwrap_err();
+    /// assert_eq!(err.index(), 2);
+    /// assert_eq!(err.into_error(), "unreadable");
+    /// ```
+    fn with_index_context(self) -> WithIndexContext<'static, Self>
+    where
+        Self: Sized,
+    {
+        WithIndexContext::new(self)
+    }
+
+    /// Like [`with_index_context`], but additionally labels each error using
+    /// the provided closure. The closure is only called when an error occurs.
+    ///
+    /// [`with_index_context`]: TryIteratorExt::with_index_context
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tryiter::TryIteratorExt;
+    ///
+    /// let file = "users.csv";
+    /// let records = vec![Ok(1), Err("truncated row")];
+    /// let mut iter = records
+    ///     .into_iter()
+    ///     .context_err(|| format!("reading {}", file));
+    ///
+    /// assert_eq!(iter.next(), Some(Ok(1)));
+    ///
+    /// let err = iter.next().unwrap().unwrap_err();
+    /// assert_eq!(err.label().map(String::as_str), Some("reading users.csv"));
+    /// assert_eq!(err.to_string(), "reading users.csv (element 1): truncated row");
+    /// ```
+    fn context_err<L, F>(self, f: F) -> ContextErr<'static, Self, F>
+    where
+        Self: Sized,
+        F: FnMut() -> L,
+    {
+        ContextErr::new(self, f)
+    }
+
+    /// Creates an iterator which yields at most one error and then returns
+    /// `None` forever, without polling the underlying iterator again.
+    ///
+    /// The terminating error can also be kept out-of-band by iterating over
+    /// [`FuseOnErr::oks`] and retrieved afterwards with
+    /// [`FuseOnErr::take_error`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tryiter::TryIteratorExt;
+    ///
+    /// let mut iter = vec![Ok(1), Err("error"), Ok(2)]
+    ///     .into_iter()
+    ///     .fuse_on_err()
+    ///     .map_ok(|x| Ok(x * 10));
+    ///
+    /// assert_eq!(iter.next(), Some(Ok(10)));
+    /// assert_eq!(iter.next(), Some(Err("error")));
+    /// assert_eq!(iter.next(), None);
+    /// assert_eq!(iter.next(), None);
+    /// ```
+    fn fuse_on_err(self) -> FuseOnErr<Self>
+    where
+        Self: Sized,
+    {
+        FuseOnErr::new(self)
+    }
+
+    /// Converts this iterator into a [`TryStream`] which yields the same
+    /// results. The iterator is polled synchronously, the stream is always
+    /// ready.
+    ///
+    /// [`TryStream`]: futures::TryStream
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use futures::executor::block_on;
+    /// use futures::TryStreamExt;
+    /// use tryiter::TryIteratorExt;
+    ///
+    /// let stream = vec![Ok(1), Ok(2), Err("error")]
+    ///     .into_iter()
+    ///     .into_try_stream()
+    ///     .map_ok(|x| x * 2);
+    ///
+    /// let doubled: Result<Vec<_>, _> = block_on(stream.try_collect());
+    /// assert_eq!(doubled, Err("error"));
+    /// ```
+    #[cfg(feature = "futures")]
+    fn into_try_stream(self) -> futures::stream::Iter<Self>
+    where
+        Self: Sized,
+    {
+        futures::stream::iter(self)
     }
 }
@@ -0,0 +1,487 @@
+use alloc::vec::{self, Vec};
+use core::cmp::Ordering;
+use core::fmt;
+use core::iter::FusedIterator;
+use core::marker::PhantomData;
+use core::mem;
+use std::fs::File;
+use std::io::{self, BufRead, BufReader, BufWriter, Write};
+use std::path::{Path, PathBuf};
+
+use tempfile::{NamedTempFile, TempPath};
+
+use crate::sorted::try_sort_by;
+use crate::TryIterator;
+
+/// The default number of elements held in memory by an [`ExternalSort`].
+const DEFAULT_RUN_LEN: usize = 1 << 20;
+
+/// The default number of runs merged at once by an [`ExternalSort`].
+const DEFAULT_FAN_IN: usize = 64;
+
+/// Serializes the values spilled to temporary files by an external sort.
+///
+/// See [`try_external_sorted_by`] for an example.
+///
+/// [`try_external_sorted_by`]: crate::TryIteratorExt::try_external_sorted_by
+pub trait SortCodec<T> {
+    /// The error returned when a value can not be encoded or decoded,
+    /// including I/O errors of the writer or reader.
+    type Error;
+
+    /// Writes `value` to `writer`.
+    fn encode(&mut self, value: &T, writer: &mut dyn Write) -> Result<(), Self::Error>;
+
+    /// Reads the next value written by [`encode`] from `reader`, or returns
+    /// `None` at the end of the file.
+    ///
+    /// [`encode`]: SortCodec::encode
+    fn decode(&mut self, reader: &mut dyn BufRead) -> Result<Option<T>, Self::Error>;
+}
+
+/// The configuration of an external sort, passed to
+/// [`try_external_sorted_by`].
+///
+/// [`try_external_sorted_by`]: crate::TryIteratorExt::try_external_sorted_by
+#[derive(Clone, Debug)]
+pub struct ExternalSort<C> {
+    codec: C,
+    run_len: usize,
+    fan_in: usize,
+    temp_dir: Option<PathBuf>,
+}
+
+impl<C> ExternalSort<C> {
+    /// Creates a configuration spilling values with `codec`, which holds up
+    /// to 2<sup>20</sup> values in memory and merges up to 64 runs at once.
+    pub fn new(codec: C) -> Self {
+        Self {
+            codec,
+            run_len: DEFAULT_RUN_LEN,
+            fan_in: DEFAULT_FAN_IN,
+            temp_dir: None,
+        }
+    }
+
+    /// Sets the number of values sorted in memory before being spilled to a
+    /// temporary file.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `run_len` is 0.
+    pub fn run_len(mut self, run_len: usize) -> Self {
+        assert!(run_len != 0, "run length must be non-zero");
+        self.run_len = run_len;
+        self
+    }
+
+    /// Sets the maximum number of runs merged at once, which bounds the
+    /// number of temporary files open at the same time. When there are more
+    /// runs, they are first merged in several passes through intermediate
+    /// temporary files.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `fan_in` is less than 2.
+    pub fn fan_in(mut self, fan_in: usize) -> Self {
+        assert!(fan_in >= 2, "fan-in must be at least 2");
+        self.fan_in = fan_in;
+        self
+    }
+
+    /// Creates the temporary files in `dir` instead of the default temporary
+    /// directory.
+    pub fn temp_dir(mut self, dir: impl Into<PathBuf>) -> Self {
+        self.temp_dir = Some(dir.into());
+        self
+    }
+}
+
+/// An error returned by an external sort.
+#[derive(Debug)]
+#[non_exhaustive]
+pub enum ExternalSortError<E, C> {
+    /// The underlying iterator or the comparison function returned an error.
+    Source(E),
+    /// The [`SortCodec`] failed to encode or decode a value.
+    Codec(C),
+    /// A temporary file could not be created or written.
+    Io(io::Error),
+}
+
+impl<E: fmt::Display, C: fmt::Display> fmt::Display for ExternalSortError<E, C> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ExternalSortError::Source(e) => e.fmt(f),
+            ExternalSortError::Codec(e) => {
+                write!(f, "failed to encode or decode a sorted run: {e}")
+            }
+            ExternalSortError::Io(e) => write!(f, "failed to spill a sorted run: {e}"),
+        }
+    }
+}
+
+impl<E, C> core::error::Error for ExternalSortError<E, C>
+where
+    E: core::error::Error + 'static,
+    C: core::error::Error + 'static,
+{
+    fn source(&self) -> Option<&(dyn core::error::Error + 'static)> {
+        match self {
+            ExternalSortError::Source(e) => Some(e),
+            ExternalSortError::Codec(e) => Some(e),
+            ExternalSortError::Io(e) => Some(e),
+        }
+    }
+}
+
+/// A sorted run, either spilled to a temporary file or kept in memory.
+enum Run<T> {
+    Memory(Vec<T>),
+    /// A spilled run, whose file is closed until it is merged.
+    File(TempPath),
+}
+
+/// A sorted run being merged.
+enum RunReader<T> {
+    Memory(vec::IntoIter<T>),
+    File {
+        reader: BufReader<File>,
+        /// Removes the file once the run is exhausted or dropped.
+        _path: TempPath,
+    },
+}
+
+impl<T> RunReader<T> {
+    fn open(run: Run<T>) -> io::Result<Self> {
+        Ok(match run {
+            Run::Memory(values) => RunReader::Memory(values.into_iter()),
+            Run::File(path) => RunReader::File {
+                reader: BufReader::new(File::open(&path)?),
+                _path: path,
+            },
+        })
+    }
+
+    fn next<C: SortCodec<T>>(&mut self, codec: &mut C) -> Result<Option<T>, C::Error> {
+        match self {
+            RunReader::Memory(iter) => Ok(iter.next()),
+            RunReader::File { reader, .. } => {
+                let next = codec.decode(reader)?;
+                if next.is_none() {
+                    // close and remove the exhausted file early
+                    *self = RunReader::Memory(Vec::new().into_iter());
+                }
+                Ok(next)
+            }
+        }
+    }
+}
+
+/// The smallest value not yet yielded of a run.
+struct Head<T> {
+    value: T,
+    run: usize,
+}
+
+/// A k-way merge of sorted runs.
+struct Merge<T> {
+    runs: Vec<RunReader<T>>,
+    /// A min-heap of the heads of the runs, ordered by value then run.
+    heap: Vec<Head<T>>,
+}
+
+impl<T> Merge<T> {
+    fn new<E, C, F>(
+        runs: Vec<Run<T>>,
+        codec: &mut C,
+        compare: &mut F,
+    ) -> Result<Self, ExternalSortError<E, C::Error>>
+    where
+        C: SortCodec<T>,
+        F: FnMut(&T, &T) -> Result<Ordering, E>,
+    {
+        let runs = runs
+            .into_iter()
+            .map(RunReader::open)
+            .collect::<io::Result<_>>()
+            .map_err(ExternalSortError::Io)?;
+        let mut merge = Self {
+            runs,
+            heap: Vec::new(),
+        };
+        for run in 0..merge.runs.len() {
+            let next = merge.runs[run].next(codec);
+            if let Some(value) = next.map_err(ExternalSortError::Codec)? {
+                merge.heap.push(Head { value, run });
+                merge.sift_up(merge.heap.len() - 1, compare)?;
+            }
+        }
+        Ok(merge)
+    }
+
+    fn pop<E, C, F>(
+        &mut self,
+        codec: &mut C,
+        compare: &mut F,
+    ) -> Result<Option<T>, ExternalSortError<E, C::Error>>
+    where
+        C: SortCodec<T>,
+        F: FnMut(&T, &T) -> Result<Ordering, E>,
+    {
+        let Some(head) = self.heap.first_mut() else {
+            return Ok(None);
+        };
+        let next = self.runs[head.run].next(codec);
+        let value = match next.map_err(ExternalSortError::Codec)? {
+            Some(next) => mem::replace(&mut head.value, next),
+            None => self.heap.swap_remove(0).value,
+        };
+        self.sift_down(0, compare)?;
+        Ok(Some(value))
+    }
+
+    fn sift_up<E, F, CE>(
+        &mut self,
+        mut i: usize,
+        compare: &mut F,
+    ) -> Result<(), ExternalSortError<E, CE>>
+    where
+        F: FnMut(&T, &T) -> Result<Ordering, E>,
+    {
+        while i > 0 {
+            let parent = (i - 1) / 2;
+            if !self.less(i, parent, compare)? {
+                break;
+            }
+            self.heap.swap(i, parent);
+            i = parent;
+        }
+        Ok(())
+    }
+
+    fn sift_down<E, F, CE>(
+        &mut self,
+        mut i: usize,
+        compare: &mut F,
+    ) -> Result<(), ExternalSortError<E, CE>>
+    where
+        F: FnMut(&T, &T) -> Result<Ordering, E>,
+    {
+        loop {
+            let mut child = 2 * i + 1;
+            if child >= self.heap.len() {
+                return Ok(());
+            }
+            if child + 1 < self.heap.len() && self.less(child + 1, child, compare)? {
+                child += 1;
+            }
+            if !self.less(child, i, compare)? {
+                return Ok(());
+            }
+            self.heap.swap(i, child);
+            i = child;
+        }
+    }
+
+    /// Whether the head at `a` is yielded before the head at `b`. Ties are
+    /// broken by run so that the merge is stable.
+    fn less<E, F, CE>(
+        &self,
+        a: usize,
+        b: usize,
+        compare: &mut F,
+    ) -> Result<bool, ExternalSortError<E, CE>>
+    where
+        F: FnMut(&T, &T) -> Result<Ordering, E>,
+    {
+        let (a, b) = (&self.heap[a], &self.heap[b]);
+        Ok(
+            match compare(&a.value, &b.value).map_err(ExternalSortError::Source)? {
+                Ordering::Less => true,
+                Ordering::Greater => false,
+                Ordering::Equal => a.run < b.run,
+            },
+        )
+    }
+}
+
+/// An iterator over the values of an external sort, merging its sorted runs.
+///
+/// This `struct` is created by the [`try_external_sorted_by`] method on
+/// [`TryIteratorExt`]. See its documentation for more.
+///
+/// [`try_external_sorted_by`]: crate::TryIteratorExt::try_external_sorted_by
+/// [`TryIteratorExt`]: crate::TryIteratorExt
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct ExternalSorted<T, E, C, F> {
+    merge: Merge<T>,
+    codec: C,
+    compare: F,
+    done: bool,
+    error: PhantomData<fn() -> E>,
+}
+
+impl<T, E, C, F> ExternalSorted<T, E, C, F>
+where
+    C: SortCodec<T>,
+    F: FnMut(&T, &T) -> Result<Ordering, E>,
+{
+    pub(crate) fn new<I>(
+        iter: I,
+        config: ExternalSort<C>,
+        mut compare: F,
+    ) -> Result<Self, ExternalSortError<E, C::Error>>
+    where
+        I: TryIterator<Ok = T, Err = E>,
+    {
+        let ExternalSort {
+            mut codec,
+            run_len,
+            fan_in,
+            temp_dir,
+        } = config;
+        let temp_dir = temp_dir.as_deref();
+
+        let mut iter = iter.fuse();
+        let mut runs = Vec::new();
+        loop {
+            let run = try_sort_by(iter.by_ref().take(run_len), &mut compare)
+                .map_err(ExternalSortError::Source)?;
+            if run.len() < run_len {
+                // the last run is merged from memory
+                if !run.is_empty() {
+                    runs.push(Run::Memory(run));
+                }
+                break;
+            }
+            runs.push(spill(run, &mut codec, temp_dir)?);
+        }
+
+        while runs.len() > fan_in {
+            runs = merge_pass(runs, fan_in, &mut codec, &mut compare, temp_dir)?;
+        }
+        Ok(Self {
+            merge: Merge::new(runs, &mut codec, &mut compare)?,
+            codec,
+            compare,
+            done: false,
+            error: PhantomData,
+        })
+    }
+}
+
+/// Merges each group of `fan_in` consecutive runs into an intermediate
+/// temporary file, keeping the runs in order so that the sort stays stable.
+fn merge_pass<T, E, C, F>(
+    runs: Vec<Run<T>>,
+    fan_in: usize,
+    codec: &mut C,
+    compare: &mut F,
+    temp_dir: Option<&Path>,
+) -> Result<Vec<Run<T>>, ExternalSortError<E, C::Error>>
+where
+    C: SortCodec<T>,
+    F: FnMut(&T, &T) -> Result<Ordering, E>,
+{
+    let mut merged = Vec::with_capacity(runs.len().div_ceil(fan_in));
+    let mut runs = runs.into_iter().peekable();
+    while runs.peek().is_some() {
+        let group: Vec<_> = runs.by_ref().take(fan_in).collect();
+        if group.len() == 1 {
+            merged.extend(group);
+            continue;
+        }
+        let mut merge = Merge::new(group, codec, compare)?;
+        merged.push(write_run(codec, temp_dir, |codec| {
+            merge.pop(codec, compare)
+        })?);
+    }
+    Ok(merged)
+}
+
+/// Writes a sorted run to a new temporary file.
+fn spill<T, E, C>(
+    run: Vec<T>,
+    codec: &mut C,
+    temp_dir: Option<&Path>,
+) -> Result<Run<T>, ExternalSortError<E, C::Error>>
+where
+    C: SortCodec<T>,
+{
+    let mut values = run.into_iter();
+    write_run(codec, temp_dir, |_| Ok(values.next()))
+}
+
+/// Writes the values returned by `next` to a new temporary file, which is
+/// closed until the run is merged.
+fn write_run<T, E, C>(
+    codec: &mut C,
+    temp_dir: Option<&Path>,
+    mut next: impl FnMut(&mut C) -> Result<Option<T>, ExternalSortError<E, C::Error>>,
+) -> Result<Run<T>, ExternalSortError<E, C::Error>>
+where
+    C: SortCodec<T>,
+{
+    let file = match temp_dir {
+        Some(dir) => NamedTempFile::new_in(dir),
+        None => NamedTempFile::new(),
+    };
+    let mut writer = BufWriter::new(file.map_err(ExternalSortError::Io)?);
+    while let Some(value) = next(codec)? {
+        codec
+            .encode(&value, &mut writer)
+            .map_err(ExternalSortError::Codec)?;
+    }
+    let file = writer
+        .into_inner()
+        .map_err(|e| ExternalSortError::Io(e.into_error()))?;
+    Ok(Run::File(file.into_temp_path()))
+}
+
+impl<T, E, C: fmt::Debug, F> fmt::Debug for ExternalSorted<T, E, C, F> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ExternalSorted")
+            .field("runs", &self.merge.runs.len())
+            .field("codec", &self.codec)
+            .field("done", &self.done)
+            .finish()
+    }
+}
+
+impl<T, E, C, F> Iterator for ExternalSorted<T, E, C, F>
+where
+    C: SortCodec<T>,
+    F: FnMut(&T, &T) -> Result<Ordering, E>,
+{
+    type Item = Result<T, ExternalSortError<E, C::Error>>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.done {
+            return None;
+        }
+        let next = self
+            .merge
+            .pop(&mut self.codec, &mut self.compare)
+            .transpose();
+        if !matches!(next, Some(Ok(_))) {
+            self.done = true;
+        }
+        next
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        if self.done {
+            (0, Some(0))
+        } else {
+            (usize::from(!self.merge.heap.is_empty()), None)
+        }
+    }
+}
+
+impl<T, E, C, F> FusedIterator for ExternalSorted<T, E, C, F>
+where
+    C: SortCodec<T>,
+    F: FnMut(&T, &T) -> Result<Ordering, E>,
+{
+}
@@ -0,0 +1,189 @@
+use core::fmt;
+use core::iter::FusedIterator;
+
+use crate::{ErrorSlot, Shunt, TryIterator};
+
+/// An iterator that yields at most one error and then returns `None`
+/// forever. The underlying iterator is dropped as soon as it yields an error
+/// or is exhausted, so it is never polled again.
+///
+/// This `struct` is created by the [`fuse_on_err`] method on
+/// [`TryIteratorExt`]. See its documentation for more.
+///
+/// [`fuse_on_err`]: crate::TryIteratorExt::fuse_on_err
+/// [`TryIteratorExt`]: crate::TryIteratorExt
+#[derive(Clone, Debug)]
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct FuseOnErr<I: TryIterator> {
+    iter: Option<I>,
+    /// The terminating error, when it was not yielded by `next()`.
+    slot: ErrorSlot<I::Err>,
+}
+
+impl<I: TryIterator> FuseOnErr<I> {
+    pub(crate) fn new(iter: I) -> Self {
+        Self {
+            iter: Some(iter),
+            slot: ErrorSlot::new(),
+        }
+    }
+
+    /// Returns a borrowing iterator over the Ok values. It ends at the first
+    /// error, which is kept to be retrieved with [`take_error`].
+    ///
+    /// [`take_error`]: FuseOnErr::take_error
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tryiter::TryIteratorExt;
+    ///
+    /// let mut rows = vec![Ok(1), Ok(2), Err("connection lost"), Ok(3)]
+    ///     .into_iter()
+    ///     .fuse_on_err();
+    ///
+    /// let mut sum = 0;
+    /// for row in rows.oks() {
+    ///     sum += row;
+    /// }
+    ///
+    /// assert_eq!(sum, 3);
+    /// assert_eq!(rows.take_error(), Some("connection lost"));
+    /// assert_eq!(rows.next(), None);
+    /// ```
+    pub fn oks(&mut self) -> OksUntilErr<'_, I> {
+        OksUntilErr {
+            shunt: self.slot.shunt(FuseRef(&mut self.iter)),
+        }
+    }
+
+    /// Takes the error which terminated iteration through [`oks`], if any.
+    /// Errors yielded by `next()` are returned to the caller directly and
+    /// are not kept.
+    ///
+    /// [`oks`]: FuseOnErr::oks
+    pub fn take_error(&mut self) -> Option<I::Err> {
+        self.slot.take()
+    }
+
+    /// Returns `true` once the underlying iterator yielded an error or was
+    /// exhausted.
+    pub fn is_terminated(&self) -> bool {
+        self.iter.is_none()
+    }
+}
+
+impl<I: TryIterator> Iterator for FuseOnErr<I> {
+    type Item = I::Item;
+
+    #[inline]
+    fn next(&mut self) -> Option<I::Item> {
+        FuseRef(&mut self.iter).next()
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        match self.iter {
+            Some(ref iter) => iter.size_hint(),
+            None => (0, Some(0)),
+        }
+    }
+}
+
+impl<I: TryIterator + DoubleEndedIterator> DoubleEndedIterator for FuseOnErr<I> {
+    #[inline]
+    fn next_back(&mut self) -> Option<I::Item> {
+        FuseRef(&mut self.iter).next_back()
+    }
+}
+
+impl<I: TryIterator> FusedIterator for FuseOnErr<I> {}
+
+/// Drops the iterator of a [`FuseOnErr`] once it yields an error or is
+/// exhausted.
+#[derive(Debug)]
+struct FuseRef<'a, I>(&'a mut Option<I>);
+
+impl<I: TryIterator> FuseRef<'_, I> {
+    fn fuse(&mut self, item: Option<I::Item>) -> Option<I::Item> {
+        if !matches!(item, Some(Ok(_))) {
+            *self.0 = None;
+        }
+        item
+    }
+}
+
+impl<I: TryIterator> Iterator for FuseRef<'_, I> {
+    type Item = I::Item;
+
+    #[inline]
+    fn next(&mut self) -> Option<I::Item> {
+        let item = self.0.as_mut()?.next();
+        self.fuse(item)
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        match self.0 {
+            Some(ref iter) => iter.size_hint(),
+            None => (0, Some(0)),
+        }
+    }
+}
+
+impl<I: TryIterator + DoubleEndedIterator> DoubleEndedIterator for FuseRef<'_, I> {
+    #[inline]
+    fn next_back(&mut self) -> Option<I::Item> {
+        let item = self.0.as_mut()?.next_back();
+        self.fuse(item)
+    }
+}
+
+impl<I: TryIterator> FusedIterator for FuseRef<'_, I> {}
+
+/// A borrowing iterator over the Ok values of a [`FuseOnErr`], which stores
+/// the terminating error out-of-band.
+///
+/// This `struct` is created by the [`oks`] method on [`FuseOnErr`]. See its
+/// documentation for more.
+///
+/// [`oks`]: FuseOnErr::oks
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct OksUntilErr<'a, I: TryIterator> {
+    shunt: Shunt<'a, FuseRef<'a, I>>,
+}
+
+impl<I> fmt::Debug for OksUntilErr<'_, I>
+where
+    I: TryIterator + fmt::Debug,
+    I::Err: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("OksUntilErr")
+            .field("shunt", &self.shunt)
+            .finish()
+    }
+}
+
+impl<I: TryIterator> Iterator for OksUntilErr<'_, I> {
+    type Item = I::Ok;
+
+    #[inline]
+    fn next(&mut self) -> Option<I::Ok> {
+        self.shunt.next()
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.shunt.size_hint()
+    }
+}
+
+impl<I: TryIterator + DoubleEndedIterator> DoubleEndedIterator for OksUntilErr<'_, I> {
+    #[inline]
+    fn next_back(&mut self) -> Option<I::Ok> {
+        self.shunt.next_back()
+    }
+}
+
+impl<I: TryIterator> FusedIterator for OksUntilErr<'_, I> {}
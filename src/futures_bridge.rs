@@ -0,0 +1,94 @@
+use core::fmt;
+use core::iter::FusedIterator;
+
+use futures::executor::{block_on_stream, BlockingStream};
+use futures::{Stream, TryStream};
+
+/// Converts a [`TryStream`] into a [`TryIterator`] which blocks the current
+/// thread on a local executor until each result is available.
+///
+/// The stream must be [`Unpin`]; pin it with [`Box::pin`] or
+/// [`futures::pin_mut!`] first if it is not. Once the stream ends, the
+/// iterator returns `None` without polling it again.
+///
+/// [`Box::pin`]: std::boxed::Box::pin
+/// [`TryIterator`]: crate::TryIterator
+///
+/// # Examples
+///
+/// ```
+/// use futures::stream::{self, TryStreamExt};
+/// use tryiter::{block_on_try_stream, TryIteratorExt};
+///
+/// let stream = stream::iter(vec![Ok(1), Ok(2), Err("error")]).map_ok(|x| x * 2);
+/// let mut iter = block_on_try_stream(stream).map_ok(|x| Ok(x + 1));
+///
+/// assert_eq!(iter.try_next(), Ok(Some(3)));
+/// assert_eq!(iter.try_next(), Ok(Some(5)));
+/// assert_eq!(iter.try_next(), Err("error"));
+/// assert_eq!(iter.try_next(), Ok(None));
+/// ```
+pub fn block_on_try_stream<S>(stream: S) -> BlockingTryStream<S>
+where
+    S: TryStream + Stream<Item = Result<S::Ok, S::Error>> + Unpin,
+{
+    BlockingTryStream {
+        inner: block_on_stream(stream),
+        done: false,
+    }
+}
+
+/// An iterator which blocks the current thread on each result of a
+/// [`TryStream`].
+///
+/// This `struct` is created by the [`block_on_try_stream`] function. See its
+/// documentation for more.
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct BlockingTryStream<S: Stream + Unpin> {
+    inner: BlockingStream<S>,
+    done: bool,
+}
+
+impl<S: Stream + Unpin> BlockingTryStream<S> {
+    /// Consumes this iterator, returning the underlying stream.
+    pub fn into_inner(self) -> S {
+        self.inner.into_inner()
+    }
+}
+
+impl<S: Stream + Unpin + fmt::Debug> fmt::Debug for BlockingTryStream<S> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("BlockingTryStream")
+            .field("inner", &self.inner)
+            .field("done", &self.done)
+            .finish()
+    }
+}
+
+impl<S, T, E> Iterator for BlockingTryStream<S>
+where
+    S: Stream<Item = Result<T, E>> + Unpin,
+{
+    type Item = Result<T, E>;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.done {
+            return None;
+        }
+        let item = self.inner.next();
+        self.done = item.is_none();
+        item
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        if self.done {
+            (0, Some(0))
+        } else {
+            self.inner.size_hint()
+        }
+    }
+}
+
+impl<S, T, E> FusedIterator for BlockingTryStream<S> where S: Stream<Item = Result<T, E>> + Unpin {}
@@ -0,0 +1,340 @@
+use core::cell::Cell;
+use core::fmt;
+use core::iter::FusedIterator;
+
+use crate::TryIterator;
+
+/// An error yielded by a [`TryIterator`] along with the zero-based position
+/// of the element which produced it and an optional label.
+///
+/// [`TryIterator`]: crate::TryIterator
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct IndexedError<E, L = &'static str> {
+    index: usize,
+    label: Option<L>,
+    error: E,
+}
+
+impl<E> IndexedError<E> {
+    /// Wraps an error produced by the element at position `index`.
+    pub fn new(index: usize, error: E) -> Self {
+        Self {
+            index,
+            label: None,
+            error,
+        }
+    }
+}
+
+impl<E, L> IndexedError<E, L> {
+    /// Wraps an error produced by the element at position `index`, attaching
+    /// a label describing the context in which it occurred.
+    pub fn with_label(index: usize, error: E, label: L) -> Self {
+        Self {
+            index,
+            label: Some(label),
+            error,
+        }
+    }
+
+    /// The zero-based position of the element which produced the error.
+    pub fn index(&self) -> usize {
+        self.index
+    }
+
+    /// The label describing the context in which the error occurred, if any.
+    pub fn label(&self) -> Option<&L> {
+        self.label.as_ref()
+    }
+
+    /// A reference to the underlying error.
+    pub fn error(&self) -> &E {
+        &self.error
+    }
+
+    /// Consumes this wrapper, returning the underlying error.
+    pub fn into_error(self) -> E {
+        self.error
+    }
+}
+
+impl<E: fmt::Display, L: fmt::Display> fmt::Display for IndexedError<E, L> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.label {
+            Some(ref label) => write!(f, "{} (element {}): {}", label, self.index, self.error),
+            None => write!(f, "element {}: {}", self.index, self.error),
+        }
+    }
+}
+
+impl<E, L> core::error::Error for IndexedError<E, L>
+where
+    E: core::error::Error + 'static,
+    L: fmt::Display + fmt::Debug,
+{
+    fn source(&self) -> Option<&(dyn core::error::Error + 'static)> {
+        Some(&self.error)
+    }
+}
+
+/// Records the positions of the elements pulled from a source iterator, so
+/// that errors produced further down a pipeline can be traced back to the
+/// input record which caused them.
+///
+/// Wrap the source with [`track`], build the pipeline on top of it, and wrap
+/// the pipeline with [`with_index_context`] or [`context_err`]. Each error is
+/// then labeled with the position of the last element pulled from the source,
+/// which is the element that produced it for adapters pulling one element at
+/// a time, such as [`map_ok`] and [`try_filter`], even when earlier elements
+/// were filtered out. Adapters which buffer elements ahead, such as
+/// [`try_peekable`], may report a later position.
+///
+/// [`track`]: IndexTracker::track
+/// [`with_index_context`]: IndexTracker::with_index_context
+/// [`context_err`]: IndexTracker::context_err
+/// [`map_ok`]: crate::TryIteratorExt::map_ok
+/// [`try_filter`]: crate::TryIteratorExt::try_filter
+/// [`try_peekable`]: crate::TryIteratorExt::try_peekable
+///
+/// # Examples
+///
+/// ```
+/// use tryiter::{IndexTracker, TryIteratorExt};
+///
+/// let records = vec!["7", "-1", "x", "-2", "30"];
+///
+/// let tracker = IndexTracker::new();
+/// let pipeline = tracker
+///     .track(records.into_iter().map(str::parse::<i32>))
+///     .map_err(|e| e.to_string())
+///     .try_filter(|x| Ok(*x > 0))
+///     .map_ok(|x| if x < 10 { Ok(x) } else { Err(format!("{x} is too large")) });
+///
+/// let results: Vec<_> = tracker.with_index_context(pipeline).collect();
+/// assert_eq!(results[0], Ok(7));
+/// assert_eq!(results[1].as_ref().unwrap_err().index(), 2);
+/// assert_eq!(
+///     results[2].as_ref().unwrap_err().to_string(),
+///     "element 4: 30 is too large"
+/// );
+/// ```
+#[derive(Debug, Default)]
+pub struct IndexTracker {
+    pulled: Cell<usize>,
+}
+
+impl IndexTracker {
+    /// Creates a tracker which has not seen any element.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Wraps the source iterator, recording the position of every element
+    /// pulled from it.
+    pub fn track<I: Iterator>(&self, iter: I) -> TrackIndex<'_, I> {
+        TrackIndex {
+            iter,
+            tracker: self,
+        }
+    }
+
+    /// The zero-based position of the last element pulled from the tracked
+    /// iterator, if any.
+    pub fn last_index(&self) -> Option<usize> {
+        self.pulled.get().checked_sub(1)
+    }
+
+    /// Wraps each error of `iter` in an [`IndexedError`] recording the
+    /// position of the last element pulled from the tracked iterator.
+    pub fn with_index_context<I: TryIterator>(&self, iter: I) -> WithIndexContext<'_, I> {
+        WithIndexContext {
+            iter,
+            position: Position::Tracked(self),
+        }
+    }
+
+    /// Like [`with_index_context`], but additionally labels each error using
+    /// the provided closure. The closure is only called when an error occurs.
+    ///
+    /// [`with_index_context`]: IndexTracker::with_index_context
+    pub fn context_err<I, F, L>(&self, iter: I, f: F) -> ContextErr<'_, I, F>
+    where
+        I: TryIterator,
+        F: FnMut() -> L,
+    {
+        ContextErr {
+            iter,
+            position: Position::Tracked(self),
+            f,
+        }
+    }
+}
+
+/// An iterator which records the position of the elements pulled from it in
+/// an [`IndexTracker`].
+///
+/// This `struct` is created by the [`track`] method on [`IndexTracker`]. See
+/// its documentation for more.
+///
+/// [`track`]: IndexTracker::track
+#[derive(Clone, Debug)]
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct TrackIndex<'a, I> {
+    iter: I,
+    tracker: &'a IndexTracker,
+}
+
+impl<I: Iterator> Iterator for TrackIndex<'_, I> {
+    type Item = I::Item;
+
+    #[inline]
+    fn next(&mut self) -> Option<I::Item> {
+        let item = self.iter.next()?;
+        self.tracker.pulled.set(self.tracker.pulled.get() + 1);
+        Some(item)
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+impl<I: ExactSizeIterator> ExactSizeIterator for TrackIndex<'_, I> {}
+impl<I: FusedIterator> FusedIterator for TrackIndex<'_, I> {}
+
+/// Where the position of an error comes from.
+#[derive(Clone, Copy, Debug)]
+enum Position<'a> {
+    /// The number of elements yielded by the wrapped iterator itself.
+    Counted(usize),
+    Tracked(&'a IndexTracker),
+}
+
+impl Position<'_> {
+    /// Returns the position of the element just pulled from the wrapped
+    /// iterator.
+    fn advance(&mut self) -> usize {
+        match self {
+            Position::Counted(count) => {
+                *count += 1;
+                *count - 1
+            }
+            Position::Tracked(tracker) => tracker.last_index().unwrap_or(0),
+        }
+    }
+}
+
+/// An iterator that wraps each error of the underlying iterator in an
+/// [`IndexedError`] recording its position.
+///
+/// This `struct` is created by the [`with_index_context`] method on
+/// [`TryIteratorExt`] or on [`IndexTracker`]. See their documentation for
+/// more.
+///
+/// [`with_index_context`]: crate::TryIteratorExt::with_index_context
+/// [`TryIteratorExt`]: crate::TryIteratorExt
+#[derive(Clone, Debug)]
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct WithIndexContext<'a, I> {
+    iter: I,
+    position: Position<'a>,
+}
+
+impl<I> WithIndexContext<'_, I> {
+    pub(crate) fn new(iter: I) -> Self {
+        Self {
+            iter,
+            position: Position::Counted(0),
+        }
+    }
+}
+
+impl<I: TryIterator> Iterator for WithIndexContext<'_, I> {
+    type Item = Result<I::Ok, IndexedError<I::Err>>;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        let item = self.iter.next()?;
+        let index = self.position.advance();
+        Some(item.map_err(|err| IndexedError::new(index, err)))
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+impl<I: TryIterator + ExactSizeIterator> ExactSizeIterator for WithIndexContext<'_, I> {}
+impl<I: TryIterator + FusedIterator> FusedIterator for WithIndexContext<'_, I> {}
+
+/// An iterator that wraps each error of the underlying iterator in an
+/// [`IndexedError`] recording its position and a lazily computed label.
+///
+/// This `struct` is created by the [`context_err`] method on
+/// [`TryIteratorExt`] or on [`IndexTracker`]. See their documentation for
+/// more.
+///
+/// [`context_err`]: crate::TryIteratorExt::context_err
+/// [`TryIteratorExt`]: crate::TryIteratorExt
+#[derive(Clone)]
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct ContextErr<'a, I, F> {
+    iter: I,
+    position: Position<'a>,
+    f: F,
+}
+
+impl<I, F> ContextErr<'_, I, F> {
+    pub(crate) fn new(iter: I, f: F) -> Self {
+        Self {
+            iter,
+            position: Position::Counted(0),
+            f,
+        }
+    }
+}
+
+impl<I: fmt::Debug, F> fmt::Debug for ContextErr<'_, I, F> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ContextErr")
+            .field("iter", &self.iter)
+            .field("position", &self.position)
+            .finish()
+    }
+}
+
+impl<I, F, L> Iterator for ContextErr<'_, I, F>
+where
+    I: TryIterator,
+    F: FnMut() -> L,
+{
+    type Item = Result<I::Ok, IndexedError<I::Err, L>>;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        let item = self.iter.next()?;
+        let index = self.position.advance();
+        Some(item.map_err(|err| IndexedError::with_label(index, err, (self.f)())))
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+impl<I, F, L> ExactSizeIterator for ContextErr<'_, I, F>
+where
+    I: TryIterator + ExactSizeIterator,
+    F: FnMut() -> L,
+{
+}
+
+impl<I, F, L> FusedIterator for ContextErr<'_, I, F>
+where
+    I: TryIterator + FusedIterator,
+    F: FnMut() -> L,
+{
+}
@@ -1,8 +1,76 @@
+#![no_std]
+
+#[cfg(feature = "alloc")]
+extern crate alloc;
+#[cfg(feature = "std")]
+extern crate std;
+
+mod adapters;
+#[cfg(feature = "alloc")]
+mod chunks;
+mod collect_errors;
+mod error_mode;
 mod ext;
+#[cfg(feature = "external-sort")]
+mod external_sort;
+mod fuse_on_err;
+#[cfg(feature = "futures")]
+mod futures_bridge;
+mod indexed_error;
+#[cfg(feature = "rayon")]
+pub mod parallel;
+#[cfg(feature = "alloc")]
+mod partition;
+mod reduce;
+mod shunt;
+#[cfg(feature = "alloc")]
+mod sorted;
+pub mod sources;
+mod tolerate_errors;
+mod try_flatten;
+#[cfg(feature = "alloc")]
+mod try_multipeek;
 mod try_peekable;
+#[cfg(feature = "alloc")]
+mod try_put_back;
+#[cfg(feature = "alloc")]
+mod windows;
 
+pub use adapters::{
+    ErrInto, InspectErr, InspectOk, MapErr, MapOk, TryFilter, TryFilterMap, TryMapWhile, TryScan,
+    TrySkipWhile, TryTakeWhile,
+};
+#[cfg(feature = "alloc")]
+pub use chunks::{
+    ArrayChunkPolicy, AttachPartial, ChunkError, DiscardPartial, FlushPartial, PartialChunkPolicy,
+    TryArrayChunks, TryChunks,
+};
+pub use collect_errors::ErrorCollector;
+pub use error_mode::{ErrorMode, ForwardErrors, StashErrors};
 pub use ext::TryIteratorExt;
-pub use try_peekable::TryPeekable;
+#[cfg(feature = "external-sort")]
+pub use external_sort::{ExternalSort, ExternalSortError, ExternalSorted, SortCodec};
+pub use fuse_on_err::{FuseOnErr, OksUntilErr};
+#[cfg(feature = "futures")]
+pub use futures_bridge::{block_on_try_stream, BlockingTryStream};
+pub use indexed_error::{ContextErr, IndexTracker, IndexedError, TrackIndex, WithIndexContext};
+#[cfg(feature = "rayon")]
+pub use parallel::TryParallelIteratorExt;
+#[cfg(feature = "alloc")]
+pub use partition::{PartitionErr, PartitionOk};
+pub use reduce::{CheckedNum, MinMaxResult, ReduceError};
+pub use shunt::{ErrorSlot, Shunt};
+#[cfg(feature = "alloc")]
+pub use tolerate_errors::MaxErrorRate;
+pub use tolerate_errors::{ErrorBudgetExceeded, ErrorPolicy, MaxErrors, TolerateErrors};
+pub use try_flatten::{TryFlatMap, TryFlatten};
+#[cfg(feature = "alloc")]
+pub use try_multipeek::TryMultiPeek;
+pub use try_peekable::{TryPeekable, TryPeekingTakeWhile};
+#[cfg(feature = "alloc")]
+pub use try_put_back::TryPutBack;
+#[cfg(feature = "alloc")]
+pub use windows::{TryTupleWindows, TryWindows, TupleWindow};
 
 use private::Sealed;
 
@@ -0,0 +1,336 @@
+//! Parallel counterparts of the [`TryIteratorExt`] methods for rayon's
+//! [`ParallelIterator`]s of [`Result`]s.
+//!
+//! [`TryIteratorExt`]: crate::TryIteratorExt
+
+use core::fmt;
+
+use rayon::iter::plumbing::{Consumer, ProducerCallback, UnindexedConsumer};
+use rayon::iter::{FromParallelIterator, IndexedParallelIterator, ParallelIterator};
+
+/// Parallel counterpart of [`TryIteratorExt`] for rayon's
+/// [`ParallelIterator`]s of [`Result`]s.
+///
+/// The methods share their names and semantics with [`TryIteratorExt`], with
+/// one difference: when several elements fail, the error which wins is the
+/// first one observed by any thread, which is not necessarily the first one in
+/// iteration order. Short-circuiting methods cancel the remaining work as soon
+/// as their outcome is known.
+///
+/// This trait is sealed and implemented for every [`ParallelIterator`] of
+/// [`Result`]s.
+///
+/// [`TryIteratorExt`]: crate::TryIteratorExt
+pub trait TryParallelIteratorExt:
+    ParallelIterator<Item = Result<Self::Ok, Self::Err>> + private::Sealed
+{
+    /// The type of successful values yielded by this iterator
+    type Ok: Send;
+
+    /// The type of failures yielded by this iterator
+    type Err: Send;
+
+    /// Maps the success values using the provided closure. Errors are passed
+    /// through.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rayon::prelude::*;
+    /// use tryiter::TryParallelIteratorExt;
+    ///
+    /// let v: Vec<_> = vec![Ok(5), Err(5)]
+    ///     .into_par_iter()
+    ///     .map_ok(|x| Ok(x * 2))
+    ///     .collect();
+    ///
+    /// assert_eq!(v, vec![Ok(10), Err(5)]);
+    /// ```
+    fn map_ok<T, F>(self, f: F) -> MapOk<Self, F>
+    where
+        T: Send,
+        F: Fn(Self::Ok) -> Result<T, Self::Err> + Sync + Send,
+    {
+        MapOk { base: self, f }
+    }
+
+    /// Filters and maps the success values using the provided closure. Errors
+    /// are passed through.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rayon::prelude::*;
+    /// use tryiter::TryParallelIteratorExt;
+    ///
+    /// let halves: Vec<_> = vec![Ok(1), Ok(6), Err("error")]
+    ///     .into_par_iter()
+    ///     .try_filter_map(|x| Ok(if x % 2 == 0 { Some(x / 2) } else { None }))
+    ///     .collect();
+    ///
+    /// assert_eq!(halves, vec![Ok(3), Err("error")]);
+    /// ```
+    fn try_filter_map<T, F>(self, f: F) -> TryFilterMap<Self, F>
+    where
+        T: Send,
+        F: Fn(Self::Ok) -> Result<Option<T>, Self::Err> + Sync + Send,
+    {
+        TryFilterMap { base: self, f }
+    }
+
+    /// Returns `true` if the provided closure returns `true` for all success
+    /// values in the iterator. Errors are passed through.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rayon::prelude::*;
+    /// use tryiter::TryParallelIteratorExt;
+    ///
+    /// let v = vec![Ok::<i32, i32>(1), Ok(2), Ok(3)];
+    /// assert_eq!(v.into_par_iter().try_all(|x| Ok(x < 4)), Ok(true));
+    ///
+    /// let v = vec![Ok(1), Err("error"), Ok(3)];
+    /// assert_eq!(v.into_par_iter().try_all(|x| Ok(x < 4)), Err("error"));
+    /// ```
+    fn try_all<F>(self, f: F) -> Result<bool, Self::Err>
+    where
+        F: Fn(Self::Ok) -> Result<bool, Self::Err> + Sync + Send,
+    {
+        self.find_map_any(|result| match result.and_then(&f) {
+            Ok(true) => None,
+            Ok(false) => Some(Ok(false)),
+            Err(err) => Some(Err(err)),
+        })
+        .unwrap_or(Ok(true))
+    }
+
+    /// Returns `true` if the provided closure returns `true` for any success
+    /// values in the iterator. Errors are passed through.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rayon::prelude::*;
+    /// use tryiter::TryParallelIteratorExt;
+    ///
+    /// let v = vec![Ok::<i32, i32>(1), Ok(2), Ok(3)];
+    /// assert_eq!(v.into_par_iter().try_any(|x| Ok(x == 3)), Ok(true));
+    ///
+    /// let v = vec![Ok(1), Err("error"), Ok(3)];
+    /// assert_eq!(v.into_par_iter().try_any(|x| Ok(x == 4)), Err("error"));
+    /// ```
+    fn try_any<F>(self, f: F) -> Result<bool, Self::Err>
+    where
+        F: Fn(Self::Ok) -> Result<bool, Self::Err> + Sync + Send,
+    {
+        self.find_map_any(|result| match result.and_then(&f) {
+            Ok(true) => Some(Ok(true)),
+            Ok(false) => None,
+            Err(err) => Some(Err(err)),
+        })
+        .unwrap_or(Ok(false))
+    }
+
+    /// Returns the element that gives the maximum value from the specified
+    /// function. If several elements are equally maximum, the last element is
+    /// returned. If the iterator is empty, [`Option::None`] is returned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rayon::prelude::*;
+    /// use tryiter::TryParallelIteratorExt;
+    ///
+    /// let v = vec![Ok((5, 0)), Ok((9, 0)), Ok((7, 0)), Ok((9, -1)), Ok((8, 0))];
+    /// let max: Result<_, i32> = v.into_par_iter().try_max_by_key(|(v, _occ)| *v);
+    /// assert_eq!(max, Ok(Some((9, -1))));
+    ///
+    /// let v = vec![Ok((5, 0)), Ok((9, 0)), Err(7), Ok((8, 0))];
+    /// let max = v.into_par_iter().try_max_by_key(|(v, _occ)| *v);
+    /// assert_eq!(max, Err(7));
+    /// ```
+    fn try_max_by_key<B, F>(self, f: F) -> Result<Option<Self::Ok>, Self::Err>
+    where
+        B: Ord + Send,
+        F: Fn(&Self::Ok) -> B + Sync + Send,
+    {
+        let max = self
+            .map(|result| result.map(|v| Some((f(&v), v))))
+            .try_reduce(
+                || None,
+                |acc, x| {
+                    Ok(match (acc, x) {
+                        (Some(acc), Some(x)) => {
+                            Some(core::cmp::max_by(acc, x, |a, b| a.0.cmp(&b.0)))
+                        }
+                        (acc, x) => x.or(acc),
+                    })
+                },
+            )?;
+        Ok(max.map(|(_, v)| v))
+    }
+
+    /// Converts an iterator of [`Result`] of pairs into a [`Result`] of pair
+    /// of containers, stopping at the first error observed.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rayon::prelude::*;
+    /// use tryiter::TryParallelIteratorExt;
+    ///
+    /// let v: Vec<Result<_, String>> = vec![Ok((1, 2)), Ok((3, 4)), Ok((5, 6))];
+    /// let unzipped = v.into_par_iter().try_unzip();
+    /// assert_eq!(unzipped, Ok((vec![1, 3, 5], vec![2, 4, 6])));
+    ///
+    /// let v = vec![Ok((1, 2)), Err("No number found"), Ok((5, 6))];
+    /// let unzipped: Result<(Vec<_>, Vec<_>), _> = v.into_par_iter().try_unzip();
+    /// assert_eq!(unzipped, Err("No number found"));
+    /// ```
+    fn try_unzip<A, B, FromA, FromB>(self) -> Result<(FromA, FromB), Self::Err>
+    where
+        Self: TryParallelIteratorExt<Ok = (A, B)>,
+        A: Send,
+        B: Send,
+        FromA: Send + FromParallelIterator<A>,
+        FromB: Send + FromParallelIterator<B>,
+    {
+        self.collect::<Result<(FromA, FromB), Self::Err>>()
+    }
+}
+
+impl<P, T, E> TryParallelIteratorExt for P
+where
+    P: ParallelIterator<Item = Result<T, E>>,
+    T: Send,
+    E: Send,
+{
+    type Ok = T;
+    type Err = E;
+}
+
+mod private {
+    use rayon::iter::ParallelIterator;
+
+    pub trait Sealed {}
+    impl<P, T, E> Sealed for P where P: ParallelIterator<Item = Result<T, E>> {}
+}
+
+/// A parallel iterator that maps the success values of the underlying
+/// iterator, passing errors through.
+///
+/// This `struct` is created by the [`map_ok`] method on
+/// [`TryParallelIteratorExt`]. See its documentation for more.
+///
+/// [`map_ok`]: TryParallelIteratorExt::map_ok
+#[derive(Clone)]
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct MapOk<P, F> {
+    base: P,
+    f: F,
+}
+
+impl<P: fmt::Debug, F> fmt::Debug for MapOk<P, F> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("MapOk").field("base", &self.base).finish()
+    }
+}
+
+impl<P, F, T> ParallelIterator for MapOk<P, F>
+where
+    P: TryParallelIteratorExt,
+    T: Send,
+    F: Fn(P::Ok) -> Result<T, P::Err> + Sync + Send,
+{
+    type Item = Result<T, P::Err>;
+
+    fn drive_unindexed<C>(self, consumer: C) -> C::Result
+    where
+        C: UnindexedConsumer<Self::Item>,
+    {
+        let f = self.f;
+        self.base
+            .map(move |result| result.and_then(&f))
+            .drive_unindexed(consumer)
+    }
+
+    fn opt_len(&self) -> Option<usize> {
+        self.base.opt_len()
+    }
+}
+
+impl<P, F, T> IndexedParallelIterator for MapOk<P, F>
+where
+    P: TryParallelIteratorExt + IndexedParallelIterator,
+    T: Send,
+    F: Fn(P::Ok) -> Result<T, P::Err> + Sync + Send,
+{
+    fn drive<C>(self, consumer: C) -> C::Result
+    where
+        C: Consumer<Self::Item>,
+    {
+        let f = self.f;
+        self.base
+            .map(move |result| result.and_then(&f))
+            .drive(consumer)
+    }
+
+    fn len(&self) -> usize {
+        self.base.len()
+    }
+
+    fn with_producer<CB>(self, callback: CB) -> CB::Output
+    where
+        CB: ProducerCallback<Self::Item>,
+    {
+        let f = self.f;
+        self.base
+            .map(move |result| result.and_then(&f))
+            .with_producer(callback)
+    }
+}
+
+/// A parallel iterator that filters and maps the success values of the
+/// underlying iterator, passing errors through.
+///
+/// This `struct` is created by the [`try_filter_map`] method on
+/// [`TryParallelIteratorExt`]. See its documentation for more.
+///
+/// [`try_filter_map`]: TryParallelIteratorExt::try_filter_map
+#[derive(Clone)]
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct TryFilterMap<P, F> {
+    base: P,
+    f: F,
+}
+
+impl<P: fmt::Debug, F> fmt::Debug for TryFilterMap<P, F> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("TryFilterMap")
+            .field("base", &self.base)
+            .finish()
+    }
+}
+
+impl<P, F, T> ParallelIterator for TryFilterMap<P, F>
+where
+    P: TryParallelIteratorExt,
+    T: Send,
+    F: Fn(P::Ok) -> Result<Option<T>, P::Err> + Sync + Send,
+{
+    type Item = Result<T, P::Err>;
+
+    fn drive_unindexed<C>(self, consumer: C) -> C::Result
+    where
+        C: UnindexedConsumer<Self::Item>,
+    {
+        let f = self.f;
+        self.base
+            .filter_map(move |result| match result {
+                Ok(ok) => f(ok).transpose(),
+                Err(err) => Some(Err(err)),
+            })
+            .drive_unindexed(consumer)
+    }
+}
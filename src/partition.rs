@@ -0,0 +1,156 @@
+use alloc::collections::VecDeque;
+use alloc::rc::Rc;
+use core::cell::RefCell;
+use core::fmt;
+use core::iter::FusedIterator;
+
+use crate::TryIterator;
+
+#[derive(Debug)]
+struct Shared<I: TryIterator> {
+    iter: I,
+    oks: VecDeque<I::Ok>,
+    errs: VecDeque<I::Err>,
+}
+
+pub(crate) fn new<I: TryIterator>(iter: I) -> (PartitionOk<I>, PartitionErr<I>) {
+    let shared = Rc::new(RefCell::new(Shared {
+        iter,
+        oks: VecDeque::new(),
+        errs: VecDeque::new(),
+    }));
+    (
+        PartitionOk {
+            shared: shared.clone(),
+        },
+        PartitionErr { shared },
+    )
+}
+
+/// An iterator over the success values of a partitioned [`TryIterator`].
+///
+/// Errors pulled from the underlying iterator while searching for the next
+/// success value are buffered for the linked [`PartitionErr`], or dropped if
+/// it was dropped.
+///
+/// This `struct` is created by the [`partition_results_lazy`] method on
+/// [`TryIteratorExt`]. See its documentation for more.
+///
+/// [`partition_results_lazy`]: crate::TryIteratorExt::partition_results_lazy
+/// [`TryIteratorExt`]: crate::TryIteratorExt
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct PartitionOk<I: TryIterator> {
+    shared: Rc<RefCell<Shared<I>>>,
+}
+
+impl<I> fmt::Debug for PartitionOk<I>
+where
+    I: TryIterator + fmt::Debug,
+    I::Ok: fmt::Debug,
+    I::Err: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("PartitionOk")
+            .field("shared", &self.shared)
+            .finish()
+    }
+}
+
+impl<I: TryIterator> Iterator for PartitionOk<I> {
+    type Item = I::Ok;
+
+    fn next(&mut self) -> Option<I::Ok> {
+        let mut shared = self.shared.borrow_mut();
+        if let Some(ok) = shared.oks.pop_front() {
+            return Some(ok);
+        }
+        loop {
+            match shared.iter.next()? {
+                Ok(ok) => return Some(ok),
+                // only buffer errors while the linked half can read them
+                Err(err) if Rc::strong_count(&self.shared) > 1 => shared.errs.push_back(err),
+                Err(_) => {}
+            }
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let shared = self.shared.borrow();
+        let (_, hi) = shared.iter.size_hint();
+        let buffered = shared.oks.len();
+        (buffered, hi.and_then(|hi| hi.checked_add(buffered)))
+    }
+}
+
+impl<I: TryIterator + FusedIterator> FusedIterator for PartitionOk<I> {}
+
+impl<I: TryIterator> Drop for PartitionOk<I> {
+    fn drop(&mut self) {
+        // nothing can read the buffered success values anymore
+        self.shared.borrow_mut().oks.clear();
+    }
+}
+
+/// An iterator over the errors of a partitioned [`TryIterator`].
+///
+/// Success values pulled from the underlying iterator while searching for the
+/// next error are buffered for the linked [`PartitionOk`], or dropped if it
+/// was dropped.
+///
+/// This `struct` is created by the [`partition_results_lazy`] method on
+/// [`TryIteratorExt`]. See its documentation for more.
+///
+/// [`partition_results_lazy`]: crate::TryIteratorExt::partition_results_lazy
+/// [`TryIteratorExt`]: crate::TryIteratorExt
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct PartitionErr<I: TryIterator> {
+    shared: Rc<RefCell<Shared<I>>>,
+}
+
+impl<I> fmt::Debug for PartitionErr<I>
+where
+    I: TryIterator + fmt::Debug,
+    I::Ok: fmt::Debug,
+    I::Err: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("PartitionErr")
+            .field("shared", &self.shared)
+            .finish()
+    }
+}
+
+impl<I: TryIterator> Iterator for PartitionErr<I> {
+    type Item = I::Err;
+
+    fn next(&mut self) -> Option<I::Err> {
+        let mut shared = self.shared.borrow_mut();
+        if let Some(err) = shared.errs.pop_front() {
+            return Some(err);
+        }
+        loop {
+            match shared.iter.next()? {
+                // only buffer success values while the linked half can read them
+                Ok(ok) if Rc::strong_count(&self.shared) > 1 => shared.oks.push_back(ok),
+                Ok(_) => {}
+                Err(err) => return Some(err),
+            }
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let shared = self.shared.borrow();
+        let (_, hi) = shared.iter.size_hint();
+        let buffered = shared.errs.len();
+        (buffered, hi.and_then(|hi| hi.checked_add(buffered)))
+    }
+}
+
+impl<I: TryIterator + FusedIterator> FusedIterator for PartitionErr<I> {}
+
+impl<I: TryIterator> Drop for PartitionErr<I> {
+    fn drop(&mut self) {
+        // nothing can read the buffered errors anymore
+        self.shared.borrow_mut().errs.clear();
+    }
+}
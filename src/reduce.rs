@@ -0,0 +1,183 @@
+use core::fmt;
+
+/// An error returned by a reduction which can fail other than by an error of
+/// the underlying iterator, such as [`try_checked_sum`] or
+/// [`try_max_partial`].
+///
+/// [`try_checked_sum`]: crate::TryIteratorExt::try_checked_sum
+/// [`try_max_partial`]: crate::TryIteratorExt::try_max_partial
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ReduceError<E> {
+    /// The underlying iterator yielded an error.
+    Source(E),
+    /// The reduction overflowed the numeric type.
+    Overflow,
+    /// Two values could not be compared, such as a float and NaN.
+    Incomparable,
+}
+
+impl<E> ReduceError<E> {
+    /// Returns the error yielded by the underlying iterator, if that is what
+    /// stopped the reduction.
+    pub fn into_source(self) -> Option<E> {
+        match self {
+            ReduceError::Source(e) => Some(e),
+            _ => None,
+        }
+    }
+}
+
+impl<E: fmt::Display> fmt::Display for ReduceError<E> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ReduceError::Source(e) => e.fmt(f),
+            ReduceError::Overflow => f.write_str("arithmetic overflow"),
+            ReduceError::Incomparable => f.write_str("incomparable values"),
+        }
+    }
+}
+
+impl<E> core::error::Error for ReduceError<E>
+where
+    E: core::error::Error + 'static,
+{
+    fn source(&self) -> Option<&(dyn core::error::Error + 'static)> {
+        match self {
+            ReduceError::Source(e) => Some(e),
+            _ => None,
+        }
+    }
+}
+
+/// Rejects a reduced value which can not be compared with itself, such as
+/// NaN, so that it is not returned when it was never compared.
+pub(crate) fn self_comparable<T: PartialOrd, E>(
+    value: Option<T>,
+) -> Result<Option<T>, ReduceError<E>> {
+    match value {
+        Some(ref x) if x.partial_cmp(x).is_none() => Err(ReduceError::Incomparable),
+        value => Ok(value),
+    }
+}
+
+/// A numeric type with overflow-checked addition and multiplication, used by
+/// [`try_checked_sum`] and [`try_checked_product`].
+///
+/// It is implemented for all primitive integer types, and can be implemented
+/// for custom numeric types.
+///
+/// [`try_checked_sum`]: crate::TryIteratorExt::try_checked_sum
+/// [`try_checked_product`]: crate::TryIteratorExt::try_checked_product
+///
+/// # Examples
+///
+/// ```
+/// use tryiter::{CheckedNum, ReduceError, TryIteratorExt};
+///
+/// #[derive(Debug, PartialEq)]
+/// struct Percent(u8);
+///
+/// impl CheckedNum for Percent {
+///     fn zero() -> Self {
+///         Percent(0)
+///     }
+///
+///     fn one() -> Self {
+///         Percent(100)
+///     }
+///
+///     fn checked_add(self, rhs: Self) -> Option<Self> {
+///         self.0.checked_add(rhs.0).filter(|&p| p <= 100).map(Percent)
+///     }
+///
+///     fn checked_mul(self, rhs: Self) -> Option<Self> {
+///         let product = u16::from(self.0) * u16::from(rhs.0) / 100;
+///         u8::try_from(product).ok().map(Percent)
+///     }
+/// }
+///
+/// let shares = vec![Ok::<_, ()>(Percent(60)), Ok(Percent(30))];
+/// assert_eq!(shares.into_iter().try_checked_sum(), Ok(Percent(90)));
+///
+/// let shares = vec![Ok::<_, ()>(Percent(60)), Ok(Percent(50))];
+/// assert_eq!(shares.into_iter().try_checked_sum(), Err(ReduceError::Overflow));
+/// ```
+pub trait CheckedNum: Sized {
+    /// The identity of addition, returned by the sum of no elements.
+    fn zero() -> Self;
+
+    /// The identity of multiplication, returned by the product of no
+    /// elements.
+    fn one() -> Self;
+
+    /// Adds `rhs`, returning `None` on overflow.
+    fn checked_add(self, rhs: Self) -> Option<Self>;
+
+    /// Multiplies by `rhs`, returning `None` on overflow.
+    fn checked_mul(self, rhs: Self) -> Option<Self>;
+}
+
+macro_rules! impl_checked_num {
+    ($($t:ty)*) => {$(
+        impl CheckedNum for $t {
+            #[inline]
+            fn zero() -> Self {
+                0
+            }
+
+            #[inline]
+            fn one() -> Self {
+                1
+            }
+
+            #[inline]
+            fn checked_add(self, rhs: Self) -> Option<Self> {
+                <$t>::checked_add(self, rhs)
+            }
+
+            #[inline]
+            fn checked_mul(self, rhs: Self) -> Option<Self> {
+                <$t>::checked_mul(self, rhs)
+            }
+        }
+    )*};
+}
+
+impl_checked_num! { i8 i16 i32 i64 i128 isize u8 u16 u32 u64 u128 usize }
+
+/// The result of [`try_minmax`] and its variants.
+///
+/// [`try_minmax`]: crate::TryIteratorExt::try_minmax
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum MinMaxResult<T> {
+    /// The iterator was empty.
+    NoElements,
+    /// The iterator had exactly one element, which is both the minimum and
+    /// the maximum.
+    OneElement(T),
+    /// The iterator had several elements; the minimum and the maximum.
+    MinMax(T, T),
+}
+
+impl<T: Clone> MinMaxResult<T> {
+    /// Returns the minimum and the maximum as a pair, cloning the element if
+    /// there was only one.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tryiter::MinMaxResult;
+    ///
+    /// assert_eq!(MinMaxResult::<i32>::NoElements.into_option(), None);
+    /// assert_eq!(MinMaxResult::OneElement(1).into_option(), Some((1, 1)));
+    /// assert_eq!(MinMaxResult::MinMax(1, 2).into_option(), Some((1, 2)));
+    /// ```
+    pub fn into_option(self) -> Option<(T, T)> {
+        match self {
+            MinMaxResult::NoElements => None,
+            MinMaxResult::OneElement(x) => Some((x.clone(), x)),
+            MinMaxResult::MinMax(min, max) => Some((min, max)),
+        }
+    }
+}
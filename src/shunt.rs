@@ -0,0 +1,151 @@
+use core::fmt;
+use core::iter::FusedIterator;
+
+use crate::TryIterator;
+
+/// Holds the first error of one or more [`Shunt`]ed iterators, so that a
+/// [`TryIterator`] can drive consumers which only accept an
+/// `Iterator<Item = T>`. [`FuseOnErr`] owns its slot instead.
+///
+/// [`TryIterator`]: crate::TryIterator
+/// [`FuseOnErr`]: crate::FuseOnErr
+///
+/// # Examples
+///
+/// ```
+/// use std::collections::HashMap;
+/// use tryiter::ErrorSlot;
+///
+/// let pairs = vec![Ok(("a", 1)), Err("malformed"), Ok(("b", 2))];
+///
+/// let mut slot = ErrorSlot::new();
+/// let map: HashMap<_, _> = slot.shunt(pairs.into_iter()).collect();
+/// assert_eq!(map.len(), 1);
+/// assert_eq!(slot.into_result(map).unwrap_err(), "malformed");
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct ErrorSlot<E> {
+    error: Option<E>,
+}
+
+impl<E> ErrorSlot<E> {
+    /// Creates an empty slot.
+    pub fn new() -> Self {
+        Self { error: None }
+    }
+
+    /// Wraps `iter` in an iterator over its Ok values which stops at the
+    /// first error, storing it in this slot. If the slot already holds an
+    /// error, the returned iterator yields nothing.
+    pub fn shunt<I>(&mut self, iter: I) -> Shunt<'_, I>
+    where
+        I: TryIterator<Err = E>,
+    {
+        Shunt { iter, slot: self }
+    }
+
+    /// Returns `true` if an error was stored in this slot.
+    pub fn is_set(&self) -> bool {
+        self.error.is_some()
+    }
+
+    /// A reference to the stored error, if any.
+    pub fn error(&self) -> Option<&E> {
+        self.error.as_ref()
+    }
+
+    /// Takes the stored error out of the slot, leaving it empty.
+    pub fn take(&mut self) -> Option<E> {
+        self.error.take()
+    }
+
+    /// Returns the stored error if there is one, or `value` otherwise.
+    pub fn into_result<T>(self, value: T) -> Result<T, E> {
+        match self.error {
+            Some(error) => Err(error),
+            None => Ok(value),
+        }
+    }
+}
+
+impl<E> Default for ErrorSlot<E> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// An iterator over the Ok values of a [`TryIterator`] which stores the
+/// first error in an [`ErrorSlot`] and stops.
+///
+/// This `struct` is created by the [`shunt`] method on [`ErrorSlot`] and
+/// passed to the closure of the [`shunt`][TryIteratorExt::shunt] method on
+/// [`TryIteratorExt`]. See their documentation for more.
+///
+/// [`TryIterator`]: crate::TryIterator
+/// [`shunt`]: ErrorSlot::shunt
+/// [`TryIteratorExt`]: crate::TryIteratorExt
+/// [TryIteratorExt::shunt]: crate::TryIteratorExt::shunt
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct Shunt<'a, I: TryIterator> {
+    iter: I,
+    slot: &'a mut ErrorSlot<I::Err>,
+}
+
+impl<I> fmt::Debug for Shunt<'_, I>
+where
+    I: TryIterator + fmt::Debug,
+    I::Err: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Shunt")
+            .field("iter", &self.iter)
+            .field("slot", &self.slot)
+            .finish()
+    }
+}
+
+impl<I: TryIterator> Iterator for Shunt<'_, I> {
+    type Item = I::Ok;
+
+    #[inline]
+    fn next(&mut self) -> Option<I::Ok> {
+        if self.slot.is_set() {
+            return None;
+        }
+        match self.iter.next()? {
+            Ok(v) => Some(v),
+            Err(e) => {
+                self.slot.error = Some(e);
+                None
+            }
+        }
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        if self.slot.is_set() {
+            (0, Some(0))
+        } else {
+            let (_, hi) = self.iter.size_hint();
+            (0, hi)
+        }
+    }
+}
+
+impl<I: TryIterator + DoubleEndedIterator> DoubleEndedIterator for Shunt<'_, I> {
+    #[inline]
+    fn next_back(&mut self) -> Option<I::Ok> {
+        if self.slot.is_set() {
+            return None;
+        }
+        match self.iter.next_back()? {
+            Ok(v) => Some(v),
+            Err(e) => {
+                self.slot.error = Some(e);
+                None
+            }
+        }
+    }
+}
+
+impl<I: TryIterator + FusedIterator> FusedIterator for Shunt<'_, I> {}
@@ -0,0 +1,77 @@
+use alloc::vec::Vec;
+use core::cmp::Ordering;
+
+use crate::TryIterator;
+
+/// The length of the runs sorted by insertion before being merged.
+const RUN_LEN: usize = 16;
+
+/// Collects `iter` and sorts it with a stable merge sort which stops at the
+/// first error, whether yielded by `iter` or returned by `compare`.
+///
+/// Unlike [`slice::sort_by`], the comparison function is never called again
+/// after it fails, and an inconsistent ordering can not cause a panic.
+pub(crate) fn try_sort_by<I, F>(iter: I, compare: &mut F) -> Result<Vec<I::Ok>, I::Err>
+where
+    I: TryIterator,
+    F: FnMut(&I::Ok, &I::Ok) -> Result<Ordering, I::Err>,
+{
+    let mut runs = Vec::new();
+    let mut run = Vec::with_capacity(RUN_LEN);
+    for x in iter {
+        insert(&mut run, x?, compare)?;
+        if run.len() == RUN_LEN {
+            runs.push(core::mem::replace(&mut run, Vec::with_capacity(RUN_LEN)));
+        }
+    }
+    if !run.is_empty() {
+        runs.push(run);
+    }
+
+    while runs.len() > 1 {
+        let mut merged = Vec::with_capacity(runs.len().div_ceil(2));
+        let mut runs_iter = runs.into_iter();
+        while let Some(a) = runs_iter.next() {
+            merged.push(match runs_iter.next() {
+                Some(b) => merge(a, b, compare)?,
+                None => a,
+            });
+        }
+        runs = merged;
+    }
+    Ok(runs.pop().unwrap_or_default())
+}
+
+/// Inserts `x` into the sorted `run`, after any equal elements.
+fn insert<T, E, F>(run: &mut Vec<T>, x: T, compare: &mut F) -> Result<(), E>
+where
+    F: FnMut(&T, &T) -> Result<Ordering, E>,
+{
+    let mut i = run.len();
+    while i > 0 && compare(&x, &run[i - 1])? == Ordering::Less {
+        i -= 1;
+    }
+    run.insert(i, x);
+    Ok(())
+}
+
+/// Merges two sorted runs, taking from `a` first on ties.
+fn merge<T, E, F>(a: Vec<T>, b: Vec<T>, compare: &mut F) -> Result<Vec<T>, E>
+where
+    F: FnMut(&T, &T) -> Result<Ordering, E>,
+{
+    let mut merged = Vec::with_capacity(a.len() + b.len());
+    let mut a = a.into_iter().peekable();
+    let mut b = b.into_iter().peekable();
+    while let (Some(x), Some(y)) = (a.peek(), b.peek()) {
+        let next = if compare(y, x)? == Ordering::Less {
+            b.next()
+        } else {
+            a.next()
+        };
+        merged.extend(next);
+    }
+    merged.extend(a);
+    merged.extend(b);
+    Ok(merged)
+}
@@ -0,0 +1,466 @@
+//! Constructors for [`TryIterator`]s driven by fallible closures.
+//!
+//! Every iterator in this module stops after yielding the first error, which
+//! is what a reader or cursor typically wants once its source is broken.
+//! Call `continue_on_err()` on it to keep calling the closure instead.
+//!
+//! [`TryIterator`]: crate::TryIterator
+
+use core::fmt;
+use core::iter::FusedIterator;
+
+/// Creates an iterator which calls `f` for each element until it returns
+/// `Ok(None)`.
+///
+/// After `f` returns an error the iterator is exhausted, unless
+/// [`continue_on_err`] is called.
+///
+/// [`continue_on_err`]: TryFromFn::continue_on_err
+///
+/// # Examples
+///
+/// ```
+/// use tryiter::sources::try_from_fn;
+///
+/// let mut pages = vec![Ok(Some("page 2")), Err("timeout"), Ok(Some("page 1"))];
+/// let v: Vec<_> = try_from_fn(|| pages.pop().unwrap_or(Ok(None))).collect();
+/// assert_eq!(v, vec![Ok("page 1"), Err("timeout")]);
+/// ```
+pub fn try_from_fn<T, E, F>(f: F) -> TryFromFn<F>
+where
+    F: FnMut() -> Result<Option<T>, E>,
+{
+    TryFromFn {
+        f,
+        done: false,
+        continue_on_err: false,
+    }
+}
+
+/// An iterator which yields the results of a fallible closure.
+///
+/// This `struct` is created by the [`try_from_fn`] function. See its
+/// documentation for more.
+#[derive(Clone)]
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct TryFromFn<F> {
+    f: F,
+    done: bool,
+    continue_on_err: bool,
+}
+
+impl<F> TryFromFn<F> {
+    /// Keeps calling the closure after it returns an error.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tryiter::sources::try_from_fn;
+    ///
+    /// let mut pages = vec![Ok(Some("page 2")), Err("timeout"), Ok(Some("page 1"))];
+    /// let v: Vec<_> = try_from_fn(|| pages.pop().unwrap_or(Ok(None)))
+    ///     .continue_on_err()
+    ///     .collect();
+    /// assert_eq!(v, vec![Ok("page 1"), Err("timeout"), Ok("page 2")]);
+    /// ```
+    pub fn continue_on_err(mut self) -> Self {
+        self.continue_on_err = true;
+        self
+    }
+}
+
+impl<F> fmt::Debug for TryFromFn<F> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("TryFromFn")
+            .field("done", &self.done)
+            .field("continue_on_err", &self.continue_on_err)
+            .finish()
+    }
+}
+
+impl<T, E, F> Iterator for TryFromFn<F>
+where
+    F: FnMut() -> Result<Option<T>, E>,
+{
+    type Item = Result<T, E>;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.done {
+            return None;
+        }
+        match (self.f)() {
+            Ok(Some(v)) => Some(Ok(v)),
+            Ok(None) => {
+                self.done = true;
+                None
+            }
+            Err(e) => {
+                self.done = !self.continue_on_err;
+                Some(Err(e))
+            }
+        }
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        if self.done {
+            (0, Some(0))
+        } else {
+            (0, None)
+        }
+    }
+}
+
+impl<T, E, F> FusedIterator for TryFromFn<F> where F: FnMut() -> Result<Option<T>, E> {}
+
+/// Creates an iterator which starts at `first` and computes each following
+/// element from the previous one, until `succ` returns `Ok(None)`.
+///
+/// After `succ` returns an error the iterator is exhausted, unless
+/// [`continue_on_err`] is called.
+///
+/// [`continue_on_err`]: TrySuccessors::continue_on_err
+///
+/// # Examples
+///
+/// ```
+/// use tryiter::sources::try_successors;
+///
+/// let powers: Vec<_> = try_successors(Some(1u8), |&n| {
+///     n.checked_mul(10).map(Some).ok_or("overflow")
+/// })
+/// .collect();
+/// assert_eq!(powers, vec![Ok(1), Ok(10), Ok(100), Err("overflow")]);
+/// ```
+pub fn try_successors<T, E, F>(first: Option<T>, succ: F) -> TrySuccessors<T, E, F>
+where
+    F: FnMut(&T) -> Result<Option<T>, E>,
+{
+    TrySuccessors {
+        next: first.map(Ok),
+        succ,
+        retry: None,
+        retry_from: None,
+    }
+}
+
+/// An iterator where each element is computed from the previous one by a
+/// fallible closure.
+///
+/// This `struct` is created by the [`try_successors`] function. See its
+/// documentation for more.
+#[derive(Clone)]
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct TrySuccessors<T, E, F> {
+    next: Option<Result<T, E>>,
+    succ: F,
+    /// Clones the predecessor of an error, set by `continue_on_err()`.
+    retry: Option<fn(&T) -> T>,
+    /// The predecessor to retry `succ` with once the error is yielded.
+    retry_from: Option<T>,
+}
+
+impl<T: Clone, E, F> TrySuccessors<T, E, F> {
+    /// Keeps going after `succ` returns an error: once the error is yielded,
+    /// `succ` is called again with the same previous element. This requires
+    /// `T: Clone` to keep the previous element around.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tryiter::sources::try_successors;
+    ///
+    /// let mut attempts = 0;
+    /// let v: Vec<_> = try_successors(Some(1), |&page| {
+    ///     attempts += 1;
+    ///     match (page, attempts) {
+    ///         (1, 1) => Err("timeout"),
+    ///         (1, _) => Ok(Some(2)),
+    ///         _ => Ok(None),
+    ///     }
+    /// })
+    /// .continue_on_err()
+    /// .collect();
+    /// assert_eq!(v, vec![Ok(1), Err("timeout"), Ok(2)]);
+    /// ```
+    pub fn continue_on_err(mut self) -> Self {
+        self.retry = Some(T::clone);
+        self
+    }
+}
+
+impl<T, E, F> TrySuccessors<T, E, F>
+where
+    F: FnMut(&T) -> Result<Option<T>, E>,
+{
+    fn advance(&mut self, prev: &T) {
+        self.next = match (self.succ)(prev) {
+            Ok(next) => next.map(Ok),
+            Err(e) => {
+                self.retry_from = self.retry.map(|clone| clone(prev));
+                Some(Err(e))
+            }
+        };
+    }
+}
+
+impl<T: fmt::Debug, E: fmt::Debug, F> fmt::Debug for TrySuccessors<T, E, F> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("TrySuccessors")
+            .field("next", &self.next)
+            .field("retry_from", &self.retry_from)
+            .finish()
+    }
+}
+
+impl<T, E, F> Iterator for TrySuccessors<T, E, F>
+where
+    F: FnMut(&T) -> Result<Option<T>, E>,
+{
+    type Item = Result<T, E>;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        match self.next.take()? {
+            Ok(item) => {
+                self.advance(&item);
+                Some(Ok(item))
+            }
+            Err(e) => {
+                if let Some(prev) = self.retry_from.take() {
+                    self.advance(&prev);
+                }
+                Some(Err(e))
+            }
+        }
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        if self.next.is_some() {
+            (1, None)
+        } else {
+            (0, Some(0))
+        }
+    }
+}
+
+impl<T, E, F> FusedIterator for TrySuccessors<T, E, F> where F: FnMut(&T) -> Result<Option<T>, E> {}
+
+/// Creates an iterator which calls `f` with a mutable reference to `state`
+/// for each element, until it returns `Ok(None)`.
+///
+/// After `f` returns an error the iterator is exhausted, unless
+/// [`continue_on_err`] is called.
+///
+/// [`continue_on_err`]: TryUnfold::continue_on_err
+///
+/// # Examples
+///
+/// ```
+/// use tryiter::sources::try_unfold;
+///
+/// // a cursor over pages of a listing
+/// let pages = [Ok(vec![1, 2]), Ok(vec![3]), Err("gone"), Ok(vec![4])];
+/// let v: Vec<_> = try_unfold(0, |cursor| {
+///     let Some(page) = pages.get(*cursor) else {
+///         return Ok(None);
+///     };
+///     *cursor += 1;
+///     page.clone().map(Some)
+/// })
+/// .collect();
+/// assert_eq!(v, vec![Ok(vec![1, 2]), Ok(vec![3]), Err("gone")]);
+/// ```
+pub fn try_unfold<S, T, E, F>(state: S, f: F) -> TryUnfold<S, F>
+where
+    F: FnMut(&mut S) -> Result<Option<T>, E>,
+{
+    TryUnfold {
+        state,
+        f,
+        done: false,
+        continue_on_err: false,
+    }
+}
+
+/// An iterator which yields the results of a fallible closure driving a
+/// state machine.
+///
+/// This `struct` is created by the [`try_unfold`] function. See its
+/// documentation for more.
+#[derive(Clone)]
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct TryUnfold<S, F> {
+    state: S,
+    f: F,
+    done: bool,
+    continue_on_err: bool,
+}
+
+impl<S, F> TryUnfold<S, F> {
+    /// Keeps calling the closure after it returns an error, with the state
+    /// as the closure left it.
+    pub fn continue_on_err(mut self) -> Self {
+        self.continue_on_err = true;
+        self
+    }
+
+    /// A reference to the current state.
+    pub fn state(&self) -> &S {
+        &self.state
+    }
+
+    /// Consumes the iterator, returning the current state.
+    pub fn into_state(self) -> S {
+        self.state
+    }
+}
+
+impl<S: fmt::Debug, F> fmt::Debug for TryUnfold<S, F> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("TryUnfold")
+            .field("state", &self.state)
+            .field("done", &self.done)
+            .field("continue_on_err", &self.continue_on_err)
+            .finish()
+    }
+}
+
+impl<S, T, E, F> Iterator for TryUnfold<S, F>
+where
+    F: FnMut(&mut S) -> Result<Option<T>, E>,
+{
+    type Item = Result<T, E>;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.done {
+            return None;
+        }
+        match (self.f)(&mut self.state) {
+            Ok(Some(v)) => Some(Ok(v)),
+            Ok(None) => {
+                self.done = true;
+                None
+            }
+            Err(e) => {
+                self.done = !self.continue_on_err;
+                Some(Err(e))
+            }
+        }
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        if self.done {
+            (0, Some(0))
+        } else {
+            (0, None)
+        }
+    }
+}
+
+impl<S, T, E, F> FusedIterator for TryUnfold<S, F> where F: FnMut(&mut S) -> Result<Option<T>, E> {}
+
+/// Creates an endless iterator which calls `f` for each element.
+///
+/// After `f` returns an error the iterator is exhausted, unless
+/// [`continue_on_err`] is called.
+///
+/// [`continue_on_err`]: TryRepeatWith::continue_on_err
+///
+/// # Examples
+///
+/// ```
+/// use tryiter::sources::try_repeat_with;
+///
+/// let mut readings = vec![Err("sensor offline"), Ok(21), Ok(20)];
+/// let v: Vec<_> = try_repeat_with(|| readings.pop().unwrap()).collect();
+/// assert_eq!(v, vec![Ok(20), Ok(21), Err("sensor offline")]);
+/// ```
+pub fn try_repeat_with<T, E, F>(f: F) -> TryRepeatWith<F>
+where
+    F: FnMut() -> Result<T, E>,
+{
+    TryRepeatWith {
+        f,
+        done: false,
+        continue_on_err: false,
+    }
+}
+
+/// An endless iterator which yields the results of a fallible closure.
+///
+/// This `struct` is created by the [`try_repeat_with`] function. See its
+/// documentation for more.
+#[derive(Clone)]
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct TryRepeatWith<F> {
+    f: F,
+    done: bool,
+    continue_on_err: bool,
+}
+
+impl<F> TryRepeatWith<F> {
+    /// Keeps calling the closure after it returns an error. The iterator
+    /// then never ends.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tryiter::sources::try_repeat_with;
+    ///
+    /// let mut n = 0;
+    /// let v: Vec<_> = try_repeat_with(|| {
+    ///     n += 1;
+    ///     if n % 3 == 0 { Err(n) } else { Ok(n) }
+    /// })
+    /// .continue_on_err()
+    /// .take(4)
+    /// .collect();
+    /// assert_eq!(v, vec![Ok(1), Ok(2), Err(3), Ok(4)]);
+    /// ```
+    pub fn continue_on_err(mut self) -> Self {
+        self.continue_on_err = true;
+        self
+    }
+}
+
+impl<F> fmt::Debug for TryRepeatWith<F> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("TryRepeatWith")
+            .field("done", &self.done)
+            .field("continue_on_err", &self.continue_on_err)
+            .finish()
+    }
+}
+
+impl<T, E, F> Iterator for TryRepeatWith<F>
+where
+    F: FnMut() -> Result<T, E>,
+{
+    type Item = Result<T, E>;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.done {
+            return None;
+        }
+        let item = (self.f)();
+        self.done = item.is_err() && !self.continue_on_err;
+        Some(item)
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        match (self.done, self.continue_on_err) {
+            (true, _) => (0, Some(0)),
+            (false, true) => (usize::MAX, None),
+            (false, false) => (1, None),
+        }
+    }
+}
+
+impl<T, E, F> FusedIterator for TryRepeatWith<F> where F: FnMut() -> Result<T, E> {}
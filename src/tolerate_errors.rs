@@ -0,0 +1,260 @@
+#[cfg(feature = "alloc")]
+use alloc::collections::VecDeque;
+use core::fmt;
+use core::iter::FusedIterator;
+
+use crate::TryIterator;
+
+/// Decides which errors are tolerated by [`tolerate_errors`].
+///
+/// This trait is implemented for closures of the form `FnMut(&E) -> bool`,
+/// which tolerate every error for which they return `true`.
+///
+/// [`tolerate_errors`]: crate::TryIteratorExt::tolerate_errors
+pub trait ErrorPolicy<E> {
+    /// Called for every success value passing through the iterator.
+    fn record_ok(&mut self) {}
+
+    /// Called for every error, returns `true` if the error should be
+    /// tolerated.
+    fn tolerate(&mut self, error: &E) -> bool;
+}
+
+impl<E, F> ErrorPolicy<E> for F
+where
+    F: FnMut(&E) -> bool,
+{
+    fn tolerate(&mut self, error: &E) -> bool {
+        self(error)
+    }
+}
+
+/// An [`ErrorPolicy`] which tolerates a fixed number of errors.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct MaxErrors {
+    remaining: usize,
+}
+
+impl MaxErrors {
+    /// Tolerates up to `max` errors over the whole iterator.
+    pub fn new(max: usize) -> Self {
+        Self { remaining: max }
+    }
+}
+
+impl<E> ErrorPolicy<E> for MaxErrors {
+    fn tolerate(&mut self, _error: &E) -> bool {
+        match self.remaining.checked_sub(1) {
+            Some(remaining) => {
+                self.remaining = remaining;
+                true
+            }
+            None => false,
+        }
+    }
+}
+
+/// An [`ErrorPolicy`] which tolerates up to `max` errors within any window of
+/// `window` consecutive elements.
+#[cfg(feature = "alloc")]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct MaxErrorRate {
+    max: usize,
+    window: usize,
+    /// The number of elements seen so far.
+    position: usize,
+    /// The positions of the errors within the current window.
+    errors: VecDeque<usize>,
+}
+
+#[cfg(feature = "alloc")]
+impl MaxErrorRate {
+    /// Tolerates up to `max` errors within any `window` consecutive elements.
+    pub fn new(max: usize, window: usize) -> Self {
+        Self {
+            max,
+            window,
+            position: 0,
+            errors: VecDeque::new(),
+        }
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<E> ErrorPolicy<E> for MaxErrorRate {
+    fn record_ok(&mut self) {
+        self.position += 1;
+    }
+
+    fn tolerate(&mut self, _error: &E) -> bool {
+        let position = self.position;
+        self.position += 1;
+        while let Some(&oldest) = self.errors.front() {
+            if position - oldest < self.window {
+                break;
+            }
+            self.errors.pop_front();
+        }
+        if self.errors.len() < self.max {
+            self.errors.push_back(position);
+            true
+        } else {
+            false
+        }
+    }
+}
+
+/// The error yielded by [`TolerateErrors`] once an error is not tolerated by
+/// its [`ErrorPolicy`], summarizing the errors seen until then.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ErrorBudgetExceeded<E> {
+    error: E,
+    tolerated: usize,
+    position: usize,
+}
+
+impl<E> ErrorBudgetExceeded<E> {
+    /// The error which exceeded the budget.
+    pub fn error(&self) -> &E {
+        &self.error
+    }
+
+    /// The number of errors which were tolerated before the budget was
+    /// exceeded.
+    pub fn tolerated(&self) -> usize {
+        self.tolerated
+    }
+
+    /// The zero-based position in the underlying iterator of the error which
+    /// exceeded the budget, that is the number of elements before it.
+    pub fn position(&self) -> usize {
+        self.position
+    }
+
+    /// Consumes this wrapper, returning the error which exceeded the budget.
+    pub fn into_error(self) -> E {
+        self.error
+    }
+}
+
+impl<E: fmt::Display> fmt::Display for ErrorBudgetExceeded<E> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "error budget exceeded at element {} after tolerating {} errors: {}",
+            self.position, self.tolerated, self.error
+        )
+    }
+}
+
+impl<E> core::error::Error for ErrorBudgetExceeded<E>
+where
+    E: core::error::Error + 'static,
+{
+    fn source(&self) -> Option<&(dyn core::error::Error + 'static)> {
+        Some(&self.error)
+    }
+}
+
+/// An iterator that drops errors tolerated by an [`ErrorPolicy`] and stops
+/// after the first error which is not.
+///
+/// This `struct` is created by the [`tolerate_errors`] method on
+/// [`TryIteratorExt`]. See its documentation for more.
+///
+/// [`tolerate_errors`]: crate::TryIteratorExt::tolerate_errors
+/// [`TryIteratorExt`]: crate::TryIteratorExt
+#[derive(Clone)]
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct TolerateErrors<I, P, F> {
+    iter: I,
+    policy: P,
+    on_tolerated: F,
+    tolerated: usize,
+    /// The number of elements pulled from the underlying iterator.
+    position: usize,
+    done: bool,
+}
+
+impl<I, P, F> TolerateErrors<I, P, F> {
+    pub(crate) fn new(iter: I, policy: P, on_tolerated: F) -> Self {
+        Self {
+            iter,
+            policy,
+            on_tolerated,
+            tolerated: 0,
+            position: 0,
+            done: false,
+        }
+    }
+
+    /// The number of errors tolerated so far.
+    pub fn tolerated(&self) -> usize {
+        self.tolerated
+    }
+}
+
+impl<I: fmt::Debug, P: fmt::Debug, F> fmt::Debug for TolerateErrors<I, P, F> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("TolerateErrors")
+            .field("iter", &self.iter)
+            .field("policy", &self.policy)
+            .field("tolerated", &self.tolerated)
+            .field("position", &self.position)
+            .field("done", &self.done)
+            .finish()
+    }
+}
+
+impl<I, P, F> Iterator for TolerateErrors<I, P, F>
+where
+    I: TryIterator,
+    P: ErrorPolicy<I::Err>,
+    F: FnMut(&I::Err),
+{
+    type Item = Result<I::Ok, ErrorBudgetExceeded<I::Err>>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.done {
+            return None;
+        }
+        loop {
+            let item = self.iter.next()?;
+            self.position += 1;
+            match item {
+                Ok(ok) => {
+                    self.policy.record_ok();
+                    return Some(Ok(ok));
+                }
+                Err(err) if self.policy.tolerate(&err) => {
+                    self.tolerated += 1;
+                    (self.on_tolerated)(&err);
+                }
+                Err(error) => {
+                    self.done = true;
+                    return Some(Err(ErrorBudgetExceeded {
+                        error,
+                        tolerated: self.tolerated,
+                        position: self.position - 1,
+                    }));
+                }
+            }
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        if self.done {
+            return (0, Some(0));
+        }
+        let (_, hi) = self.iter.size_hint();
+        (0, hi)
+    }
+}
+
+impl<I, P, F> FusedIterator for TolerateErrors<I, P, F>
+where
+    I: TryIterator + FusedIterator,
+    P: ErrorPolicy<I::Err>,
+    F: FnMut(&I::Err),
+{
+}
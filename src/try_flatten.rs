@@ -0,0 +1,261 @@
+use core::fmt;
+use core::iter::{Fuse, FusedIterator};
+
+use crate::{MapOk, TryIterator};
+
+/// An iterator that flattens each success value of the underlying iterator
+/// into a nested iterator of results.
+///
+/// This `struct` is created by the [`try_flatten`] method on
+/// [`TryIteratorExt`]. See its documentation for more.
+///
+/// [`try_flatten`]: crate::TryIteratorExt::try_flatten
+/// [`TryIteratorExt`]: crate::TryIteratorExt
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct TryFlatten<I>
+where
+    I: TryIterator,
+    I::Ok: IntoIterator,
+{
+    inner: TryFlattenCompat<I, <I::Ok as IntoIterator>::IntoIter>,
+}
+
+impl<I> TryFlatten<I>
+where
+    I: TryIterator,
+    I::Ok: IntoIterator,
+{
+    pub(crate) fn new(iter: I) -> Self {
+        Self {
+            inner: TryFlattenCompat::new(iter),
+        }
+    }
+}
+
+impl<I> Clone for TryFlatten<I>
+where
+    I: TryIterator + Clone,
+    I::Ok: IntoIterator,
+    <I::Ok as IntoIterator>::IntoIter: Clone,
+{
+    fn clone(&self) -> Self {
+        Self {
+            inner: self.inner.clone(),
+        }
+    }
+}
+
+impl<I> fmt::Debug for TryFlatten<I>
+where
+    I: TryIterator + fmt::Debug,
+    I::Ok: IntoIterator,
+    <I::Ok as IntoIterator>::IntoIter: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("TryFlatten")
+            .field("inner", &self.inner)
+            .finish()
+    }
+}
+
+impl<I, T> Iterator for TryFlatten<I>
+where
+    I: TryIterator,
+    I::Ok: IntoIterator<Item = Result<T, I::Err>>,
+{
+    type Item = Result<T, I::Err>;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        self.inner.next()
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.inner.size_hint()
+    }
+}
+
+impl<I, T> DoubleEndedIterator for TryFlatten<I>
+where
+    I: TryIterator + DoubleEndedIterator,
+    I::Ok: IntoIterator<Item = Result<T, I::Err>>,
+    <I::Ok as IntoIterator>::IntoIter: DoubleEndedIterator,
+{
+    #[inline]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.inner.next_back()
+    }
+}
+
+impl<I, T> FusedIterator for TryFlatten<I>
+where
+    I: TryIterator,
+    I::Ok: IntoIterator<Item = Result<T, I::Err>>,
+{
+}
+
+/// An iterator that maps each success value of the underlying iterator to a
+/// nested iterator of results with a fallible closure and flattens them.
+///
+/// This `struct` is created by the [`try_flat_map`] method on
+/// [`TryIteratorExt`]. See its documentation for more.
+///
+/// [`try_flat_map`]: crate::TryIteratorExt::try_flat_map
+/// [`TryIteratorExt`]: crate::TryIteratorExt
+#[derive(Clone)]
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct TryFlatMap<I, U: IntoIterator, F> {
+    inner: TryFlattenCompat<MapOk<I, F>, U::IntoIter>,
+}
+
+impl<I, U, F> TryFlatMap<I, U, F>
+where
+    I: TryIterator,
+    U: IntoIterator,
+    F: FnMut(I::Ok) -> Result<U, I::Err>,
+{
+    pub(crate) fn new(iter: I, f: F) -> Self {
+        Self {
+            inner: TryFlattenCompat::new(MapOk::new(iter, f)),
+        }
+    }
+}
+
+impl<I, U, F> fmt::Debug for TryFlatMap<I, U, F>
+where
+    I: fmt::Debug,
+    U: IntoIterator,
+    U::IntoIter: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("TryFlatMap")
+            .field("inner", &self.inner)
+            .finish()
+    }
+}
+
+impl<I, U, F, T> Iterator for TryFlatMap<I, U, F>
+where
+    I: TryIterator,
+    U: IntoIterator<Item = Result<T, I::Err>>,
+    F: FnMut(I::Ok) -> Result<U, I::Err>,
+{
+    type Item = Result<T, I::Err>;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        self.inner.next()
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.inner.size_hint()
+    }
+}
+
+impl<I, U, F, T> DoubleEndedIterator for TryFlatMap<I, U, F>
+where
+    I: TryIterator + DoubleEndedIterator,
+    U: IntoIterator<Item = Result<T, I::Err>>,
+    U::IntoIter: DoubleEndedIterator,
+    F: FnMut(I::Ok) -> Result<U, I::Err>,
+{
+    #[inline]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.inner.next_back()
+    }
+}
+
+impl<I, U, F, T> FusedIterator for TryFlatMap<I, U, F>
+where
+    I: TryIterator,
+    U: IntoIterator<Item = Result<T, I::Err>>,
+    F: FnMut(I::Ok) -> Result<U, I::Err>,
+{
+}
+
+/// Shared implementation of [`TryFlatten`] and [`TryFlatMap`], modeled after
+/// the standard library's `FlattenCompat`.
+///
+/// The outer iterator is fused and exhausted inner iterators are dropped, so
+/// both adapters are always fused.
+#[derive(Clone, Debug)]
+struct TryFlattenCompat<I, U> {
+    iter: Fuse<I>,
+    front: Option<U>,
+    back: Option<U>,
+}
+
+impl<I: Iterator, U> TryFlattenCompat<I, U> {
+    fn new(iter: I) -> Self {
+        Self {
+            iter: iter.fuse(),
+            front: None,
+            back: None,
+        }
+    }
+}
+
+fn and_then_or_clear<U: Iterator>(
+    slot: &mut Option<U>,
+    next: impl FnOnce(&mut U) -> Option<U::Item>,
+) -> Option<U::Item> {
+    let item = next(slot.as_mut()?);
+    if item.is_none() {
+        *slot = None;
+    }
+    item
+}
+
+impl<I, U, T> Iterator for TryFlattenCompat<I, U>
+where
+    I: TryIterator,
+    I::Ok: IntoIterator<IntoIter = U, Item = Result<T, I::Err>>,
+    U: Iterator<Item = Result<T, I::Err>>,
+{
+    type Item = Result<T, I::Err>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            if let Some(item) = and_then_or_clear(&mut self.front, U::next) {
+                return Some(item);
+            }
+            match self.iter.next() {
+                Some(Ok(inner)) => self.front = Some(inner.into_iter()),
+                Some(Err(err)) => return Some(Err(err)),
+                None => return and_then_or_clear(&mut self.back, U::next),
+            }
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let (flo, fhi) = self.front.as_ref().map_or((0, Some(0)), U::size_hint);
+        let (blo, bhi) = self.back.as_ref().map_or((0, Some(0)), U::size_hint);
+        let lo = flo.saturating_add(blo);
+        match (self.iter.size_hint(), fhi, bhi) {
+            ((0, Some(0)), Some(a), Some(b)) => (lo, a.checked_add(b)),
+            _ => (lo, None),
+        }
+    }
+}
+
+impl<I, U, T> DoubleEndedIterator for TryFlattenCompat<I, U>
+where
+    I: TryIterator + DoubleEndedIterator,
+    I::Ok: IntoIterator<IntoIter = U, Item = Result<T, I::Err>>,
+    U: DoubleEndedIterator<Item = Result<T, I::Err>>,
+{
+    fn next_back(&mut self) -> Option<Self::Item> {
+        loop {
+            if let Some(item) = and_then_or_clear(&mut self.back, U::next_back) {
+                return Some(item);
+            }
+            match self.iter.next_back() {
+                Some(Ok(inner)) => self.back = Some(inner.into_iter()),
+                Some(Err(err)) => return Some(Err(err)),
+                None => return and_then_or_clear(&mut self.front, U::next_back),
+            }
+        }
+    }
+}
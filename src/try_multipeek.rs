@@ -0,0 +1,246 @@
+use alloc::collections::VecDeque;
+use core::iter::FusedIterator;
+use core::marker::PhantomData;
+
+use crate::{ErrorMode, ForwardErrors, StashErrors, TryIterator};
+
+/// An iterator which can peek at an arbitrary number of Ok values ahead
+/// without consuming them.
+///
+/// Peeked elements are kept in a ring buffer. [`try_peek`] advances a peek
+/// cursor through the buffer, [`try_peek_nth`] looks at any position
+/// directly and [`reset_peek`] moves the cursor back to the front. Consuming
+/// an element with `next()` also resets the cursor.
+///
+/// What happens to an error encountered while peeking is selected by the
+/// `M` parameter. By default ([`ForwardErrors`]) the error is returned by the
+/// peek and dropped, like [`TryPeekable`] does. After calling
+/// [`stash_errors`] ([`StashErrors`]) it is buffered in its position
+/// instead.
+///
+/// This `struct` is created by the [`try_multipeek`] method on
+/// [`TryIteratorExt`]. See its documentation for more.
+///
+/// [`try_peek`]: TryMultiPeek::try_peek
+/// [`try_peek_nth`]: TryMultiPeek::try_peek_nth
+/// [`reset_peek`]: TryMultiPeek::reset_peek
+/// [`stash_errors`]: TryMultiPeek::stash_errors
+/// [`TryPeekable`]: crate::TryPeekable
+/// [`try_multipeek`]: crate::TryIteratorExt::try_multipeek
+/// [`TryIteratorExt`]: crate::TryIteratorExt
+#[derive(Clone, Debug)]
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct TryMultiPeek<I: TryIterator, M: ErrorMode<I::Err> = ForwardErrors> {
+    iter: I,
+    buf: VecDeque<Result<I::Ok, M::Stored>>,
+    /// The position of the next element returned by `try_peek()`.
+    index: usize,
+    mode: PhantomData<M>,
+}
+
+impl<I: TryIterator> TryMultiPeek<I> {
+    pub(crate) fn new(iter: I) -> Self {
+        Self {
+            iter,
+            buf: VecDeque::new(),
+            index: 0,
+            mode: PhantomData,
+        }
+    }
+
+    /// Switches to the [`StashErrors`] mode, in which errors encountered
+    /// while peeking are buffered in their position. Peeking at an error
+    /// returns a clone of it, and `next()` yields it in order.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tryiter::TryIteratorExt;
+    ///
+    /// let mut iter = vec![Ok(1), Err("error"), Ok(2)]
+    ///     .into_iter()
+    ///     .try_multipeek()
+    ///     .stash_errors();
+    ///
+    /// assert_eq!(iter.try_peek_nth(2), Ok(Some(&2)));
+    /// assert_eq!(iter.try_peek_nth(1), Err("error"));
+    /// assert_eq!(iter.try_peek_nth(1), Err("error"));
+    ///
+    /// assert_eq!(iter.next(), Some(Ok(1)));
+    /// assert_eq!(iter.next(), Some(Err("error")));
+    /// assert_eq!(iter.next(), Some(Ok(2)));
+    /// ```
+    pub fn stash_errors(self) -> TryMultiPeek<I, StashErrors>
+    where
+        I::Err: Clone,
+    {
+        TryMultiPeek {
+            iter: self.iter,
+            buf: self
+                .buf
+                .into_iter()
+                .map(|v| v.map_err(|never| match never {}))
+                .collect(),
+            index: self.index,
+            mode: PhantomData,
+        }
+    }
+}
+
+impl<I: TryIterator, M: ErrorMode<I::Err>> TryMultiPeek<I, M> {
+    /// Returns a reference to the element at the peek cursor and advances
+    /// the cursor. The first call returns the next element, the second call
+    /// the one after it, and so on until [`reset_peek`] or `next()` is
+    /// called.
+    ///
+    /// [`reset_peek`]: TryMultiPeek::reset_peek
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tryiter::TryIteratorExt;
+    ///
+    /// let mut iter = vec![Ok(1), Err("error"), Ok(2)].into_iter().try_multipeek();
+    ///
+    /// assert_eq!(iter.try_peek(), Ok(Some(&1)));
+    /// // The error is returned and dropped, the cursor does not move
+    /// assert_eq!(iter.try_peek(), Err("error"));
+    /// assert_eq!(iter.try_peek(), Ok(Some(&2)));
+    /// assert_eq!(iter.try_peek(), Ok(None));
+    ///
+    /// iter.reset_peek();
+    /// assert_eq!(iter.try_peek(), Ok(Some(&1)));
+    ///
+    /// assert_eq!(iter.next(), Some(Ok(1)));
+    /// assert_eq!(iter.try_peek(), Ok(Some(&2)));
+    /// ```
+    pub fn try_peek(&mut self) -> Result<Option<&I::Ok>, I::Err> {
+        if !self.fill(self.index)? {
+            return Ok(None);
+        }
+        self.index += 1;
+        match self.buf[self.index - 1] {
+            Ok(ref v) => Ok(Some(v)),
+            Err(ref e) => Err(M::replay(e)),
+        }
+    }
+
+    /// Returns a reference to the `n`th element ahead without consuming any
+    /// element or moving the peek cursor. `try_peek_nth(0)` is the next
+    /// element.
+    ///
+    /// With [`ForwardErrors`], an error encountered before reaching the
+    /// `n`th element is returned and dropped; the Ok values peeked until
+    /// then remain buffered.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tryiter::TryIteratorExt;
+    ///
+    /// let mut iter = vec![Ok(1), Ok(2), Err("error"), Ok(3)]
+    ///     .into_iter()
+    ///     .try_multipeek();
+    ///
+    /// assert_eq!(iter.try_peek_nth(1), Ok(Some(&2)));
+    /// assert_eq!(iter.try_peek_nth(2), Err("error"));
+    /// assert_eq!(iter.try_peek_nth(2), Ok(Some(&3)));
+    /// assert_eq!(iter.try_peek_nth(3), Ok(None));
+    ///
+    /// assert_eq!(iter.next(), Some(Ok(1)));
+    /// assert_eq!(iter.try_peek_nth(0), Ok(Some(&2)));
+    /// ```
+    pub fn try_peek_nth(&mut self, n: usize) -> Result<Option<&I::Ok>, I::Err> {
+        if !self.fill(n)? {
+            return Ok(None);
+        }
+        match self.buf[n] {
+            Ok(ref v) => Ok(Some(v)),
+            Err(ref e) => Err(M::replay(e)),
+        }
+    }
+
+    /// Like [`try_peek_nth`], but returns a mutable reference to the peeked
+    /// value.
+    ///
+    /// [`try_peek_nth`]: TryMultiPeek::try_peek_nth
+    pub fn try_peek_nth_mut(&mut self, n: usize) -> Result<Option<&mut I::Ok>, I::Err> {
+        if !self.fill(n)? {
+            return Ok(None);
+        }
+        match self.buf[n] {
+            Ok(ref mut v) => Ok(Some(v)),
+            Err(ref e) => Err(M::replay(e)),
+        }
+    }
+
+    /// Buffers elements until position `n` is filled. Returns `Ok(false)` if
+    /// the underlying iterator is exhausted first.
+    fn fill(&mut self, n: usize) -> Result<bool, I::Err> {
+        while self.buf.len() <= n {
+            match self.iter.next() {
+                Some(Ok(v)) => self.buf.push_back(Ok(v)),
+                Some(Err(e)) => self.buf.push_back(Err(M::stash(e)?)),
+                None => return Ok(false),
+            }
+        }
+        Ok(true)
+    }
+}
+
+impl<I: TryIterator, M: ErrorMode<I::Err>> TryMultiPeek<I, M> {
+    /// Moves the peek cursor back to the next element.
+    pub fn reset_peek(&mut self) {
+        self.index = 0;
+    }
+}
+
+impl<I: TryIterator, M: ErrorMode<I::Err>> Iterator for TryMultiPeek<I, M> {
+    type Item = I::Item;
+
+    #[inline]
+    fn next(&mut self) -> Option<I::Item> {
+        self.index = 0;
+        match self.buf.pop_front() {
+            Some(v) => Some(v.map_err(M::restore)),
+            None => self.iter.next(),
+        }
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let (lo, hi) = self.iter.size_hint();
+        let buffered = self.buf.len();
+        (
+            lo.saturating_add(buffered),
+            hi.and_then(|hi| hi.checked_add(buffered)),
+        )
+    }
+
+    #[inline]
+    fn fold<Acc, Fold>(self, init: Acc, mut fold: Fold) -> Acc
+    where
+        Fold: FnMut(Acc, Self::Item) -> Acc,
+    {
+        let acc = self
+            .buf
+            .into_iter()
+            .map(|v| v.map_err(M::restore))
+            .fold(init, &mut fold);
+        self.iter.fold(acc, fold)
+    }
+}
+
+impl<I, M> ExactSizeIterator for TryMultiPeek<I, M>
+where
+    I: TryIterator + ExactSizeIterator,
+    M: ErrorMode<I::Err>,
+{
+}
+
+impl<I, M> FusedIterator for TryMultiPeek<I, M>
+where
+    I: TryIterator + FusedIterator,
+    M: ErrorMode<I::Err>,
+{
+}
@@ -1,12 +1,19 @@
-use std::iter::FusedIterator;
+use core::convert::Infallible;
+use core::fmt;
+use core::iter::FusedIterator;
+use core::marker::PhantomData;
 
-use crate::TryIterator;
+use crate::{ErrorMode, ForwardErrors, StashErrors, TryIterator};
 
 /// An iterator with a `try_peek()` that returns an optional reference to the next
 /// Ok value while forwarding errors. Errors are not stored by `try_peek()`
 /// such that subsequent calls to `try_peek()` will continue to consume the
 /// underlying Iterator until they reach an `I::Ok` value.
 ///
+/// Call [`stash_errors`] to switch to the [`StashErrors`] mode, in which a
+/// peeked error is retained instead: peeking again returns the same error and
+/// `next()` yields it.
+///
 /// This `struct` is created by the [`try_peekable`] method on [`TryIteratorExt`]. See its
 /// documentation for more.
 ///
@@ -14,81 +21,352 @@ use crate::TryIterator;
 /// libraries implementation of [`Peekable`] with modifications due to only
 /// peeking Ok values.
 ///
+/// [`stash_errors`]: TryPeekable::stash_errors
 /// [`try_peekable`]: crate::TryIteratorExt::try_peekable
 /// [`TryIteratorExt`]: crate::TryIteratorExt
 /// [`Peekable`]: core::iter::Peekable
 /// [`Iterator`]: core::iter::Iterator
 #[derive(Clone, Debug)]
 #[must_use = "iterators are lazy and do nothing unless consumed"]
-pub struct TryPeekable<I: TryIterator> {
+pub struct TryPeekable<I: TryIterator, M: ErrorMode<I::Err> = ForwardErrors> {
     iter: I,
-    /// Remember a peeked value, even if it was `None`.
-    peeked: Option<Option<I::Ok>>,
+    /// Remember a peeked value, even if it was `None`. Errors are only
+    /// remembered in the `StashErrors` mode.
+    peeked: Option<Option<Result<I::Ok, M::Stored>>>,
+    /// Remember a value peeked from the back of the iterator.
+    peeked_back: Option<Result<I::Ok, M::Stored>>,
+    mode: PhantomData<M>,
 }
 
 impl<I: TryIterator> TryPeekable<I> {
     pub(crate) fn new(iter: I) -> Self {
-        Self { iter, peeked: None }
+        Self {
+            iter,
+            peeked: None,
+            peeked_back: None,
+            mode: PhantomData,
+        }
     }
 
+    /// Switches to the [`StashErrors`] mode, in which a peeked error is
+    /// retained in place: subsequent peeks return a clone of it until it is
+    /// consumed by `next()`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tryiter::TryIteratorExt;
+    ///
+    /// let mut peek = vec![Ok(1), Err("error"), Ok(2)]
+    ///     .into_iter()
+    ///     .try_peekable()
+    ///     .stash_errors();
+    ///
+    /// assert_eq!(peek.try_next(), Ok(Some(1)));
+    /// assert_eq!(peek.try_peek(), Err("error"));
+    /// assert_eq!(peek.try_peek(), Err("error"));
+    /// assert_eq!(peek.next(), Some(Err("error")));
+    /// assert_eq!(peek.try_peek(), Ok(Some(&2)));
+    /// ```
+    pub fn stash_errors(self) -> TryPeekable<I, StashErrors>
+    where
+        I::Err: Clone,
+    {
+        TryPeekable {
+            iter: self.iter,
+            peeked: self.peeked.map(|v| v.map(never_stored)),
+            peeked_back: self.peeked_back.map(never_stored),
+            mode: PhantomData,
+        }
+    }
+}
+
+impl<I: TryIterator, M: ErrorMode<I::Err>> TryPeekable<I, M> {
     pub fn try_peek(&mut self) -> Result<Option<&I::Ok>, I::Err> {
-        match self.peeked {
-            Some(ref v) => Ok(v.as_ref()),
+        let peeked = match self.peeked {
+            Some(ref v) => v,
             None => match self.iter.next() {
-                Some(Ok(v)) => Ok(self.peeked.insert(Some(v)).as_ref()),
-                Some(Err(e)) => Err(e),
-                None => {
-                    self.peeked = Some(None);
-                    Ok(None)
-                }
+                Some(Ok(v)) => self.peeked.insert(Some(Ok(v))),
+                Some(Err(e)) => self.peeked.insert(Some(Err(M::stash(e)?))),
+                None => self.peeked.insert(self.peeked_back.take()),
             },
-        }
+        };
+        peeked_ok::<_, _, M>(peeked.as_ref())
     }
 
     pub fn try_peek_mut(&mut self) -> Result<Option<&mut I::Ok>, I::Err> {
-        match self.peeked {
-            Some(ref mut v) => Ok(v.as_mut()),
+        let peeked = match self.peeked {
+            Some(ref mut v) => v,
+            None => match self.iter.next() {
+                Some(Ok(v)) => self.peeked.insert(Some(Ok(v))),
+                Some(Err(e)) => self.peeked.insert(Some(Err(M::stash(e)?))),
+                None => self.peeked.insert(self.peeked_back.take()),
+            },
+        };
+        peeked_ok_mut::<_, _, M>(peeked.as_mut())
+    }
+
+    /// Consumes and returns the next Ok value of this iterator if `func`
+    /// returns `Ok(true)` for it. Otherwise the value stays peeked and
+    /// `Ok(None)` is returned.
+    ///
+    /// Errors yielded by the underlying iterator are handled as with
+    /// `try_peek()`: forwarded, or retained in the [`StashErrors`] mode. An
+    /// error returned by `func` is forwarded, leaving the value it was called
+    /// with peeked.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tryiter::TryIteratorExt;
+    ///
+    /// let mut iter = vec![Ok(0), Ok(1), Err("error"), Ok(2)]
+    ///     .into_iter()
+    ///     .try_peekable();
+    ///
+    /// // The first item of the iterator is 0; consume it.
+    /// assert_eq!(iter.try_next_if(|&x| Ok(x == 0)), Ok(Some(0)));
+    /// // The next item returned is now 1, so `try_next_if` will return `None`.
+    /// assert_eq!(iter.try_next_if(|&x| Ok(x == 0)), Ok(None));
+    /// // A failing predicate leaves the item peeked.
+    /// assert_eq!(iter.try_next_if(|_| Err("bad predicate")), Err("bad predicate"));
+    /// assert_eq!(iter.try_next_if(|&x| Ok(x == 1)), Ok(Some(1)));
+    /// // Errors from the underlying iterator are forwarded.
+    /// assert_eq!(iter.try_next_if(|_| Ok(true)), Err("error"));
+    /// assert_eq!(iter.try_next_if(|_| Ok(true)), Ok(Some(2)));
+    /// ```
+    pub fn try_next_if(
+        &mut self,
+        func: impl FnOnce(&I::Ok) -> Result<bool, I::Err>,
+    ) -> Result<Option<I::Ok>, I::Err> {
+        let next = match self.peeked.take() {
+            Some(v) => v,
             None => match self.iter.next() {
-                Some(Ok(v)) => Ok(self.peeked.insert(Some(v)).as_mut()),
-                Some(Err(e)) => Err(e),
+                Some(Ok(v)) => Some(Ok(v)),
+                Some(Err(e)) => Some(Err(M::stash(e)?)),
+                None => self.peeked_back.take(),
+            },
+        };
+        let v = match next {
+            Some(Ok(v)) => v,
+            Some(Err(e)) => {
+                let replayed = M::replay(&e);
+                self.peeked = Some(Some(Err(e)));
+                return Err(replayed);
+            }
+            None => {
+                self.peeked = Some(None);
+                return Ok(None);
+            }
+        };
+        match func(&v) {
+            Ok(true) => Ok(Some(v)),
+            Ok(false) => {
+                self.peeked = Some(Some(Ok(v)));
+                Ok(None)
+            }
+            Err(e) => {
+                self.peeked = Some(Some(Ok(v)));
+                Err(e)
+            }
+        }
+    }
+
+    /// Consumes and returns the next Ok value if it is equal to `expected`.
+    /// Errors yielded by the underlying iterator are handled as with
+    /// `try_peek()`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tryiter::TryIteratorExt;
+    ///
+    /// let mut iter = vec![Ok(0), Ok(1), Err("error")].into_iter().try_peekable();
+    ///
+    /// assert_eq!(iter.try_next_if_eq(&0), Ok(Some(0)));
+    /// assert_eq!(iter.try_next_if_eq(&0), Ok(None));
+    /// assert_eq!(iter.try_next_if_eq(&1), Ok(Some(1)));
+    /// assert_eq!(iter.try_next_if_eq(&2), Err("error"));
+    /// assert_eq!(iter.try_next_if_eq(&2), Ok(None));
+    /// ```
+    pub fn try_next_if_eq<T>(&mut self, expected: &T) -> Result<Option<I::Ok>, I::Err>
+    where
+        T: ?Sized,
+        I::Ok: PartialEq<T>,
+    {
+        self.try_next_if(|next| Ok(next == expected))
+    }
+
+    /// Returns a borrowing iterator which yields Ok values while `predicate`
+    /// returns `Ok(true)`. The first value which does not match stays peeked,
+    /// so it is still returned by the next call to `next()`.
+    ///
+    /// If `predicate` returns an error, it is yielded and the iterator stops,
+    /// leaving the value it was called with peeked. Errors from the
+    /// underlying iterator are yielded in place; with [`StashErrors`] the
+    /// iterator then stops, since the error stays peeked until consumed by
+    /// `next()`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tryiter::TryIteratorExt;
+    ///
+    /// let mut iter = vec![Ok(1), Ok(2), Err("error"), Ok(3), Ok(10), Ok(4)]
+    ///     .into_iter()
+    ///     .try_peekable();
+    ///
+    /// let small: Vec<_> = iter.try_peeking_take_while(|&x| Ok(x < 5)).collect();
+    /// assert_eq!(small, vec![Ok(1), Ok(2), Err("error"), Ok(3)]);
+    ///
+    /// // The first value which did not match is still available
+    /// assert_eq!(iter.try_next(), Ok(Some(10)));
+    /// assert_eq!(iter.try_next(), Ok(Some(4)));
+    /// ```
+    pub fn try_peeking_take_while<F>(&mut self, predicate: F) -> TryPeekingTakeWhile<'_, I, M, F>
+    where
+        F: FnMut(&I::Ok) -> Result<bool, I::Err>,
+    {
+        TryPeekingTakeWhile {
+            peekable: self,
+            predicate,
+            done: false,
+        }
+    }
+}
+
+impl<I, M> TryPeekable<I, M>
+where
+    I: TryIterator + DoubleEndedIterator,
+    M: ErrorMode<I::Err>,
+{
+    /// Returns a reference to the last Ok value of the iterator without
+    /// consuming it. Errors encountered while peeking from the back are
+    /// handled as with `try_peek()`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tryiter::TryIteratorExt;
+    ///
+    /// let mut peek = vec![Ok(1), Ok(2), Err("error")].into_iter().try_peekable();
+    ///
+    /// assert_eq!(peek.try_peek_back(), Err("error"));
+    /// assert_eq!(peek.try_peek_back(), Ok(Some(&2)));
+    /// assert_eq!(peek.try_peek(), Ok(Some(&1)));
+    ///
+    /// assert_eq!(peek.next_back(), Some(Ok(2)));
+    /// assert_eq!(peek.try_peek_back(), Ok(Some(&1)));
+    /// assert_eq!(peek.next(), Some(Ok(1)));
+    /// assert_eq!(peek.try_peek_back(), Ok(None));
+    /// ```
+    pub fn try_peek_back(&mut self) -> Result<Option<&I::Ok>, I::Err> {
+        if self.peeked_back.is_none() && !matches!(self.peeked, Some(None)) {
+            match self.iter.next_back() {
+                Some(Ok(v)) => self.peeked_back = Some(Ok(v)),
+                Some(Err(e)) => self.peeked_back = Some(Err(M::stash(e)?)),
+                None => return peeked_ok::<_, _, M>(self.peeked.as_ref().and_then(Option::as_ref)),
+            }
+        }
+        peeked_ok::<_, _, M>(self.peeked_back.as_ref())
+    }
+
+    /// Like `try_peek_back()`, but returns a mutable reference to the peeked
+    /// value.
+    pub fn try_peek_back_mut(&mut self) -> Result<Option<&mut I::Ok>, I::Err> {
+        if self.peeked_back.is_none() && !matches!(self.peeked, Some(None)) {
+            match self.iter.next_back() {
+                Some(Ok(v)) => self.peeked_back = Some(Ok(v)),
+                Some(Err(e)) => self.peeked_back = Some(Err(M::stash(e)?)),
                 None => {
-                    self.peeked = Some(None);
-                    Ok(None)
+                    return peeked_ok_mut::<_, _, M>(self.peeked.as_mut().and_then(Option::as_mut))
                 }
-            },
+            }
         }
+        peeked_ok_mut::<_, _, M>(self.peeked_back.as_mut())
+    }
+}
+
+fn peeked_ok<T, E, M: ErrorMode<E>>(
+    peeked: Option<&Result<T, M::Stored>>,
+) -> Result<Option<&T>, E> {
+    match peeked {
+        Some(Ok(v)) => Ok(Some(v)),
+        Some(Err(e)) => Err(M::replay(e)),
+        None => Ok(None),
+    }
+}
+
+fn peeked_ok_mut<T, E, M: ErrorMode<E>>(
+    peeked: Option<&mut Result<T, M::Stored>>,
+) -> Result<Option<&mut T>, E> {
+    match peeked {
+        Some(Ok(v)) => Ok(Some(v)),
+        Some(Err(e)) => Err(M::replay(e)),
+        None => Ok(None),
     }
 }
 
-impl<I: TryIterator + ExactSizeIterator> ExactSizeIterator for TryPeekable<I> {}
-impl<I: TryIterator + FusedIterator> FusedIterator for TryPeekable<I> {}
+/// Converts a value peeked in the [`ForwardErrors`] mode, which never holds
+/// an error.
+fn never_stored<T, E>(peeked: Result<T, Infallible>) -> Result<T, E> {
+    peeked.map_err(|never| match never {})
+}
+
+/// Takes a peeked value back out to yield it, restoring a stored error.
+fn unpeek<T, E, M: ErrorMode<E>>(peeked: Result<T, M::Stored>) -> Result<T, E> {
+    peeked.map_err(M::restore)
+}
+
+impl<I, M> ExactSizeIterator for TryPeekable<I, M>
+where
+    I: TryIterator + ExactSizeIterator,
+    M: ErrorMode<I::Err>,
+{
+}
+
+impl<I, M> FusedIterator for TryPeekable<I, M>
+where
+    I: TryIterator + FusedIterator,
+    M: ErrorMode<I::Err>,
+{
+}
 
-impl<I: TryIterator> Iterator for TryPeekable<I> {
+impl<I: TryIterator, M: ErrorMode<I::Err>> Iterator for TryPeekable<I, M> {
     type Item = I::Item;
 
     #[inline]
     fn next(&mut self) -> Option<I::Item> {
         match self.peeked.take() {
-            Some(Some(peeked)) => Some(Ok(peeked)),
-            Some(None) => None,
-            None => self.iter.next(),
+            Some(v) => v.map(unpeek::<_, _, M>),
+            None => match self.iter.next() {
+                None => self.peeked_back.take().map(unpeek::<_, _, M>),
+                item => item,
+            },
         }
     }
 
     #[inline]
     fn count(mut self) -> usize {
+        let back_len = usize::from(self.peeked_back.is_some());
         match self.peeked.take() {
             Some(None) => 0,
-            Some(Some(_)) => 1 + self.iter.count(),
-            None => self.iter.count(),
+            Some(Some(_)) => 1 + self.iter.count() + back_len,
+            None => self.iter.count() + back_len,
         }
     }
 
     #[inline]
     fn nth(&mut self, n: usize) -> Option<I::Item> {
+        if self.peeked_back.is_some() {
+            for _ in 0..n {
+                let _ = self.next()?;
+            }
+            return self.next();
+        }
         match self.peeked.take() {
             Some(None) => None,
-            Some(Some(v)) if n == 0 => Some(Ok(v)),
+            Some(Some(v)) if n == 0 => Some(unpeek::<_, _, M>(v)),
             Some(Some(_)) => self.iter.nth(n - 1),
             None => self.iter.nth(n),
         }
@@ -96,9 +374,12 @@ impl<I: TryIterator> Iterator for TryPeekable<I> {
 
     #[inline]
     fn last(mut self) -> Option<I::Item> {
+        if let Some(v) = self.peeked_back.take() {
+            return Some(unpeek::<_, _, M>(v));
+        }
         let peek_opt = match self.peeked.take() {
             Some(None) => return None,
-            Some(Some(v)) => Some(Ok(v)),
+            Some(Some(v)) => Some(unpeek::<_, _, M>(v)),
             None => None,
         };
         self.iter.last().or(peek_opt)
@@ -110,7 +391,7 @@ impl<I: TryIterator> Iterator for TryPeekable<I> {
             Some(None) => return (0, Some(0)),
             Some(Some(_)) => 1,
             None => 0,
-        };
+        } + usize::from(self.peeked_back.is_some());
         let (lo, hi) = self.iter.size_hint();
         let lo = lo.saturating_add(peek_len);
         let hi = match hi {
@@ -127,9 +408,134 @@ impl<I: TryIterator> Iterator for TryPeekable<I> {
     {
         let acc = match self.peeked {
             Some(None) => return init,
-            Some(Some(v)) => fold(init, Ok(v)),
+            Some(Some(v)) => fold(init, unpeek::<_, _, M>(v)),
             None => init,
         };
-        self.iter.fold(acc, fold)
+        let acc = self.iter.fold(acc, &mut fold);
+        match self.peeked_back {
+            Some(v) => fold(acc, unpeek::<_, _, M>(v)),
+            None => acc,
+        }
+    }
+}
+
+impl<I, M> DoubleEndedIterator for TryPeekable<I, M>
+where
+    I: TryIterator + DoubleEndedIterator,
+    M: ErrorMode<I::Err>,
+{
+    #[inline]
+    fn next_back(&mut self) -> Option<I::Item> {
+        if let Some(v) = self.peeked_back.take() {
+            return Some(unpeek::<_, _, M>(v));
+        }
+        match self.peeked.as_mut() {
+            Some(v @ Some(_)) => self
+                .iter
+                .next_back()
+                .or_else(|| v.take().map(unpeek::<_, _, M>)),
+            Some(None) => None,
+            None => self.iter.next_back(),
+        }
+    }
+
+    #[inline]
+    fn rfold<Acc, Fold>(self, init: Acc, mut fold: Fold) -> Acc
+    where
+        Fold: FnMut(Acc, Self::Item) -> Acc,
+    {
+        let acc = match (self.peeked_back, &self.peeked) {
+            (_, Some(None)) => return init,
+            (Some(v), _) => fold(init, unpeek::<_, _, M>(v)),
+            (None, _) => init,
+        };
+        let acc = self.iter.rfold(acc, &mut fold);
+        match self.peeked {
+            Some(Some(v)) => fold(acc, unpeek::<_, _, M>(v)),
+            _ => acc,
+        }
     }
 }
+
+/// A borrowing iterator over the leading Ok values of a [`TryPeekable`]
+/// which match a fallible predicate.
+///
+/// This `struct` is created by the [`try_peeking_take_while`] method on
+/// [`TryPeekable`]. See its documentation for more.
+///
+/// [`try_peeking_take_while`]: TryPeekable::try_peeking_take_while
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct TryPeekingTakeWhile<'a, I: TryIterator, M: ErrorMode<I::Err>, F> {
+    peekable: &'a mut TryPeekable<I, M>,
+    predicate: F,
+    done: bool,
+}
+
+impl<I, M, F> fmt::Debug for TryPeekingTakeWhile<'_, I, M, F>
+where
+    I: TryIterator + fmt::Debug,
+    I::Ok: fmt::Debug,
+    I::Err: fmt::Debug,
+    M: ErrorMode<I::Err> + fmt::Debug,
+    M::Stored: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("TryPeekingTakeWhile")
+            .field("peekable", &self.peekable)
+            .field("done", &self.done)
+            .finish()
+    }
+}
+
+impl<I, M, F> Iterator for TryPeekingTakeWhile<'_, I, M, F>
+where
+    I: TryIterator,
+    M: ErrorMode<I::Err>,
+    F: FnMut(&I::Ok) -> Result<bool, I::Err>,
+{
+    type Item = I::Item;
+
+    fn next(&mut self) -> Option<I::Item> {
+        if self.done {
+            return None;
+        }
+        match self.peekable.try_peek() {
+            Ok(Some(v)) => match (self.predicate)(v) {
+                Ok(true) => self.peekable.next(),
+                Ok(false) => {
+                    self.done = true;
+                    None
+                }
+                Err(e) => {
+                    self.done = true;
+                    Some(Err(e))
+                }
+            },
+            Ok(None) => {
+                self.done = true;
+                None
+            }
+            Err(e) => {
+                // a stashed error would be returned again by every peek
+                self.done = matches!(self.peekable.peeked, Some(Some(Err(_))));
+                Some(Err(e))
+            }
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        if self.done {
+            return (0, Some(0));
+        }
+        let (_, hi) = self.peekable.size_hint();
+        (0, hi)
+    }
+}
+
+impl<I, M, F> FusedIterator for TryPeekingTakeWhile<'_, I, M, F>
+where
+    I: TryIterator,
+    M: ErrorMode<I::Err>,
+    F: FnMut(&I::Ok) -> Result<bool, I::Err>,
+{
+}
@@ -0,0 +1,111 @@
+use alloc::vec::Vec;
+use core::iter::FusedIterator;
+
+use crate::TryIterator;
+
+/// An iterator which allows Ok values to be put back, to be yielded again
+/// before the rest of the underlying iterator.
+///
+/// Only Ok values can be put back. Put back values are yielded before
+/// anything else, including errors still pending in the underlying iterator.
+///
+/// This `struct` is created by the [`try_put_back`] method on
+/// [`TryIteratorExt`]. See its documentation for more.
+///
+/// [`try_put_back`]: crate::TryIteratorExt::try_put_back
+/// [`TryIteratorExt`]: crate::TryIteratorExt
+#[derive(Clone, Debug)]
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct TryPutBack<I: TryIterator> {
+    iter: I,
+    /// Put back values, the next one to yield last.
+    stack: Vec<I::Ok>,
+}
+
+impl<I: TryIterator> TryPutBack<I> {
+    pub(crate) fn new(iter: I) -> Self {
+        Self {
+            iter,
+            stack: Vec::new(),
+        }
+    }
+
+    /// Puts a value back, it will be the next element yielded.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tryiter::TryIteratorExt;
+    ///
+    /// let mut iter = vec![Ok(1), Err("error")].into_iter().try_put_back();
+    ///
+    /// let first = iter.try_next()?.unwrap();
+    /// iter.put_back(first);
+    /// iter.put_back(0);
+    ///
+    /// assert_eq!(iter.try_next(), Ok(Some(0)));
+    /// assert_eq!(iter.try_next(), Ok(Some(1)));
+    /// assert_eq!(iter.try_next(), Err("error"));
+    /// # Ok::<(), &str>(())
+    /// ```
+    pub fn put_back(&mut self, value: I::Ok) {
+        self.stack.push(value);
+    }
+
+    /// Puts several values back, they will be the next elements yielded in
+    /// the order in which `values` yields them.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tryiter::TryIteratorExt;
+    ///
+    /// let mut iter = vec![Ok(3), Err("error")].into_iter().try_put_back();
+    /// iter.put_back_n([1, 2]);
+    ///
+    /// let v: Vec<_> = iter.collect();
+    /// assert_eq!(v, vec![Ok(1), Ok(2), Ok(3), Err("error")]);
+    /// ```
+    pub fn put_back_n<T>(&mut self, values: T)
+    where
+        T: IntoIterator<Item = I::Ok>,
+    {
+        let start = self.stack.len();
+        self.stack.extend(values);
+        self.stack[start..].reverse();
+    }
+}
+
+impl<I: TryIterator> Iterator for TryPutBack<I> {
+    type Item = I::Item;
+
+    #[inline]
+    fn next(&mut self) -> Option<I::Item> {
+        match self.stack.pop() {
+            Some(v) => Some(Ok(v)),
+            None => self.iter.next(),
+        }
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let (lo, hi) = self.iter.size_hint();
+        let buffered = self.stack.len();
+        (
+            lo.saturating_add(buffered),
+            hi.and_then(|hi| hi.checked_add(buffered)),
+        )
+    }
+
+    #[inline]
+    fn fold<Acc, Fold>(self, init: Acc, mut fold: Fold) -> Acc
+    where
+        Fold: FnMut(Acc, Self::Item) -> Acc,
+    {
+        let acc = self.stack.into_iter().rev().map(Ok).fold(init, &mut fold);
+        self.iter.fold(acc, fold)
+    }
+}
+
+impl<I: TryIterator + ExactSizeIterator> ExactSizeIterator for TryPutBack<I> {}
+impl<I: TryIterator + FusedIterator> FusedIterator for TryPutBack<I> {}
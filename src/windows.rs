@@ -0,0 +1,245 @@
+use alloc::collections::VecDeque;
+use alloc::vec::Vec;
+use core::iter::FusedIterator;
+use core::marker::PhantomData;
+
+use crate::TryIterator;
+
+/// A tuple of `N` elements of the same type, which can be built from a
+/// window of `N` values by [`try_tuple_windows`].
+///
+/// This trait is sealed and implemented for tuples of one to six elements.
+///
+/// [`try_tuple_windows`]: crate::TryIteratorExt::try_tuple_windows
+pub trait TupleWindow: private::Sealed + Sized {
+    /// The type of the elements of the tuple.
+    type Item: Clone;
+
+    /// The number of elements of the tuple.
+    #[doc(hidden)]
+    const LEN: usize;
+
+    /// Builds the tuple from a full window.
+    #[doc(hidden)]
+    fn from_window(window: &VecDeque<Self::Item>) -> Self;
+}
+
+mod private {
+    pub trait Sealed {}
+}
+
+macro_rules! item {
+    ($i:tt) => {
+        A
+    };
+}
+
+macro_rules! impl_tuple_window {
+    ($($len:literal => ($($i:tt)+),)+) => {$(
+        impl<A: Clone> private::Sealed for ($(item!($i),)+) {}
+
+        impl<A: Clone> TupleWindow for ($(item!($i),)+) {
+            type Item = A;
+            const LEN: usize = $len;
+
+            fn from_window(window: &VecDeque<A>) -> Self {
+                ($(window[$i].clone(),)+)
+            }
+        }
+    )+};
+}
+
+impl_tuple_window! {
+    1 => (0),
+    2 => (0 1),
+    3 => (0 1 2),
+    4 => (0 1 2 3),
+    5 => (0 1 2 3 4),
+    6 => (0 1 2 3 4 5),
+}
+
+/// A ring buffer holding the last `size` success values.
+#[derive(Clone, Debug)]
+struct Window<T> {
+    buf: VecDeque<T>,
+    size: usize,
+    reset_on_err: bool,
+}
+
+impl<T> Window<T> {
+    fn new(size: usize) -> Self {
+        assert!(size != 0, "window size must be non-zero");
+        Self {
+            buf: VecDeque::with_capacity(size),
+            size,
+            reset_on_err: false,
+        }
+    }
+
+    /// Pulls elements until the window is full or an error arrives.
+    fn advance<I>(&mut self, iter: &mut I) -> Option<Result<&VecDeque<T>, I::Err>>
+    where
+        I: TryIterator<Ok = T>,
+    {
+        loop {
+            match iter.next()? {
+                Ok(v) => {
+                    if self.buf.len() == self.size {
+                        self.buf.pop_front();
+                    }
+                    self.buf.push_back(v);
+                    if self.buf.len() == self.size {
+                        return Some(Ok(&self.buf));
+                    }
+                }
+                Err(e) => {
+                    if self.reset_on_err {
+                        self.buf.clear();
+                    }
+                    return Some(Err(e));
+                }
+            }
+        }
+    }
+
+    fn size_hint(&self, (lo, hi): (usize, Option<usize>)) -> (usize, Option<usize>) {
+        if self.reset_on_err {
+            return (0, hi);
+        }
+        let to_fill = (self.size - 1).saturating_sub(self.buf.len());
+        (lo.saturating_sub(to_fill), hi)
+    }
+}
+
+/// An iterator over overlapping windows of the success values of the
+/// underlying iterator, passing errors through.
+///
+/// This `struct` is created by the [`try_windows`] method on
+/// [`TryIteratorExt`]. See its documentation for more.
+///
+/// [`try_windows`]: crate::TryIteratorExt::try_windows
+/// [`TryIteratorExt`]: crate::TryIteratorExt
+#[derive(Clone, Debug)]
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct TryWindows<I: TryIterator> {
+    iter: I,
+    window: Window<I::Ok>,
+}
+
+impl<I: TryIterator> TryWindows<I> {
+    pub(crate) fn new(iter: I, size: usize) -> Self {
+        Self {
+            iter,
+            window: Window::new(size),
+        }
+    }
+
+    /// Clears the window when an error is encountered, so that no window
+    /// spans values from both sides of an error.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tryiter::TryIteratorExt;
+    ///
+    /// let windows: Vec<_> = vec![Ok(1), Ok(2), Err("error"), Ok(3), Ok(4)]
+    ///     .into_iter()
+    ///     .try_windows(2)
+    ///     .reset_on_err()
+    ///     .collect();
+    /// assert_eq!(windows, vec![Ok(vec![1, 2]), Err("error"), Ok(vec![3, 4])]);
+    /// ```
+    pub fn reset_on_err(mut self) -> Self {
+        self.window.reset_on_err = true;
+        self
+    }
+}
+
+impl<I> Iterator for TryWindows<I>
+where
+    I: TryIterator,
+    I::Ok: Clone,
+{
+    type Item = Result<Vec<I::Ok>, I::Err>;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        let window = self.window.advance(&mut self.iter)?;
+        Some(window.map(|window| window.iter().cloned().collect()))
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.window.size_hint(self.iter.size_hint())
+    }
+}
+
+impl<I> FusedIterator for TryWindows<I>
+where
+    I: TryIterator + FusedIterator,
+    I::Ok: Clone,
+{
+}
+
+/// An iterator over overlapping windows of the success values of the
+/// underlying iterator as tuples, passing errors through.
+///
+/// This `struct` is created by the [`try_tuple_windows`] method on
+/// [`TryIteratorExt`]. See its documentation for more.
+///
+/// [`try_tuple_windows`]: crate::TryIteratorExt::try_tuple_windows
+/// [`TryIteratorExt`]: crate::TryIteratorExt
+#[derive(Clone, Debug)]
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct TryTupleWindows<I: TryIterator, T> {
+    iter: I,
+    window: Window<I::Ok>,
+    tuple: PhantomData<fn() -> T>,
+}
+
+impl<I, T> TryTupleWindows<I, T>
+where
+    I: TryIterator,
+    T: TupleWindow<Item = I::Ok>,
+{
+    pub(crate) fn new(iter: I) -> Self {
+        Self {
+            iter,
+            window: Window::new(T::LEN),
+            tuple: PhantomData,
+        }
+    }
+
+    /// Clears the window when an error is encountered, so that no window
+    /// spans values from both sides of an error.
+    pub fn reset_on_err(mut self) -> Self {
+        self.window.reset_on_err = true;
+        self
+    }
+}
+
+impl<I, T> Iterator for TryTupleWindows<I, T>
+where
+    I: TryIterator,
+    T: TupleWindow<Item = I::Ok>,
+{
+    type Item = Result<T, I::Err>;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        let window = self.window.advance(&mut self.iter)?;
+        Some(window.map(T::from_window))
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.window.size_hint(self.iter.size_hint())
+    }
+}
+
+impl<I, T> FusedIterator for TryTupleWindows<I, T>
+where
+    I: TryIterator + FusedIterator,
+    T: TupleWindow<Item = I::Ok>,
+{
+}
@@ -0,0 +1,169 @@
+use tryiter::{MapOk, TryFilter, TryIteratorExt};
+
+type Source = std::vec::IntoIter<Result<i32, &'static str>>;
+type Double = fn(i32) -> Result<i32, &'static str>;
+type Small = fn(&i32) -> Result<bool, &'static str>;
+
+// Adapters are nameable so pipelines can be stored in struct fields.
+struct Pipeline {
+    iter: TryFilter<MapOk<Source, Double>, Small>,
+}
+
+fn double(x: i32) -> Result<i32, &'static str> {
+    Ok(x * 2)
+}
+
+fn small(x: &i32) -> Result<bool, &'static str> {
+    Ok(*x < 6)
+}
+
+#[test]
+fn test_named_adapters() {
+    let source = vec![Ok(1), Err("error"), Ok(2), Ok(3)].into_iter();
+    let mut pipeline = Pipeline {
+        iter: source.map_ok(double as Double).try_filter(small as Small),
+    };
+
+    let cloned = pipeline.iter.clone();
+    assert_eq!(cloned.collect::<Vec<_>>(), vec![Ok(2), Err("error"), Ok(4)]);
+    assert_eq!(pipeline.iter.try_next(), Ok(Some(2)));
+    assert_eq!(
+        format!("{:?}", pipeline.iter),
+        "TryFilter { iter: MapOk { iter: IntoIter([Err(\"error\"), Ok(2), Ok(3)]) } }"
+    );
+}
+
+#[test]
+fn test_adapter_size_hints() {
+    let vals: Vec<Result<i32, i32>> = vec![Ok(1), Err(2), Ok(3)];
+
+    let iter = vals.clone().into_iter().map_ok(|x| Ok(x + 1));
+    assert_eq!(iter.len(), 3);
+    let iter = vals.clone().into_iter().map_err(|x| x + 1);
+    assert_eq!(iter.len(), 3);
+    let iter = vals.clone().into_iter().err_into::<i64>();
+    assert_eq!(iter.len(), 3);
+    let iter = vals.clone().into_iter().inspect_ok(|_| {});
+    assert_eq!(iter.len(), 3);
+    let iter = vals.clone().into_iter().inspect_err(|_| {});
+    assert_eq!(iter.len(), 3);
+
+    let iter = vals.clone().into_iter().try_filter(|_| Ok(true));
+    assert_eq!(iter.size_hint(), (0, Some(3)));
+    let iter = vals.into_iter().try_filter_map(|x| Ok(Some(x)));
+    assert_eq!(iter.size_hint(), (0, Some(3)));
+}
+
+#[test]
+fn test_try_scan() {
+    // running total which fails on overflow and stops at a sentinel
+    let vals: Vec<Result<u8, &str>> = vec![Ok(100), Err("io"), Ok(100), Ok(100), Ok(0), Ok(1)];
+    let totals: Vec<_> = vals
+        .into_iter()
+        .try_scan(0u8, |total, x| {
+            if x == 0 {
+                return Ok(None);
+            }
+            *total = total.checked_add(x).ok_or("overflow")?;
+            Ok(Some(*total))
+        })
+        .collect();
+    assert_eq!(totals, vec![Ok(100), Err("io"), Ok(200), Err("overflow")]);
+}
+
+#[test]
+fn test_try_map_while() {
+    let vals: Vec<Result<&str, String>> = vec![Ok("1"), Ok("x"), Ok("2"), Ok(""), Ok("3")];
+    let mut iter = vals.into_iter().try_map_while(|s| {
+        if s.is_empty() {
+            return Ok(None);
+        }
+        s.parse::<i32>().map(Some).map_err(|e| e.to_string())
+    });
+    assert_eq!(iter.size_hint(), (0, Some(5)));
+    assert_eq!(iter.next(), Some(Ok(1)));
+    assert_eq!(
+        iter.next(),
+        Some(Err("invalid digit found in string".to_string()))
+    );
+    assert_eq!(iter.next(), Some(Ok(2)));
+    assert_eq!(iter.next(), None);
+}
+
+#[test]
+fn test_try_scan_and_map_while_are_fused() {
+    let vals: Vec<Result<i32, &str>> = vec![Ok(1), Ok(0), Ok(2), Err("error")];
+
+    let mut pulled = 0;
+    let mut iter = vals
+        .clone()
+        .into_iter()
+        .inspect(|_| pulled += 1)
+        .try_scan((), |_, x| Ok((x != 0).then_some(x)));
+    assert_eq!(iter.next(), Some(Ok(1)));
+    assert_eq!(iter.next(), None);
+    assert_eq!(iter.size_hint(), (0, Some(0)));
+    assert_eq!(iter.next(), None);
+    drop(iter);
+    assert_eq!(pulled, 2);
+
+    let mut iter = vals
+        .into_iter()
+        .try_map_while(|x| Ok((x != 0).then_some(x)));
+    assert_eq!(iter.next(), Some(Ok(1)));
+    assert_eq!(iter.next(), None);
+    assert_eq!(iter.size_hint(), (0, Some(0)));
+    assert_eq!(iter.next(), None);
+}
+
+#[test]
+fn test_try_take_while_predicate_error() {
+    let vals: Vec<Result<i32, &str>> = vec![Ok(1), Ok(2), Ok(3), Ok(1)];
+    let mut iter = vals
+        .into_iter()
+        .try_take_while(|&x| if x == 3 { Err("three") } else { Ok(true) });
+    assert_eq!(iter.next(), Some(Ok(1)));
+    assert_eq!(iter.next(), Some(Ok(2)));
+    assert_eq!(iter.next(), Some(Err("three")));
+    assert_eq!(iter.size_hint(), (0, Some(0)));
+    assert_eq!(iter.next(), None);
+    assert_eq!(iter.next(), None);
+}
+
+#[test]
+fn test_try_skip_while_source_errors() {
+    let vals: Vec<Result<i32, &str>> = vec![Err("a"), Ok(1), Err("b"), Ok(5), Err("c"), Ok(1)];
+    let rest: Vec<_> = vals
+        .clone()
+        .into_iter()
+        .try_skip_while(|&x| Ok(x < 4))
+        .collect();
+    assert_eq!(rest, vec![Err("a"), Err("b"), Ok(5), Err("c"), Ok(1)]);
+
+    // a predicate error ends the skipping phase
+    let rest: Vec<_> = vals
+        .into_iter()
+        .try_skip_while(|&x| if x == 1 { Err("one") } else { Ok(true) })
+        .collect();
+    assert_eq!(
+        rest,
+        vec![Err("a"), Err("one"), Err("b"), Ok(5), Err("c"), Ok(1)]
+    );
+}
+
+#[test]
+fn test_take_skip_while_are_fused() {
+    // an iterator which resumes after returning None
+    let mut calls = 0;
+    let unfused = std::iter::from_fn(move || {
+        calls += 1;
+        match calls {
+            2 => None,
+            _ => Some(Ok::<_, ()>(calls)),
+        }
+    });
+    let mut iter = unfused.try_skip_while(|_| Ok(false));
+    assert_eq!(iter.next(), Some(Ok(1)));
+    assert_eq!(iter.next(), None);
+    assert_eq!(iter.next(), None);
+}
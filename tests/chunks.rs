@@ -0,0 +1,106 @@
+#![cfg(feature = "alloc")]
+
+use tryiter::TryIteratorExt;
+
+fn records() -> Vec<Result<i32, &'static str>> {
+    vec![
+        Ok(1),
+        Ok(2),
+        Ok(3),
+        Err("a"),
+        Err("b"),
+        Ok(4),
+        Ok(5),
+        Ok(6),
+        Ok(7),
+    ]
+}
+
+#[test]
+fn test_try_chunks_policies() {
+    let flushed: Vec<_> = records().into_iter().try_chunks(2).collect();
+    assert_eq!(
+        flushed,
+        vec![
+            Ok(vec![1, 2]),
+            Ok(vec![3]),
+            Err("a"),
+            Err("b"),
+            Ok(vec![4, 5]),
+            Ok(vec![6, 7]),
+        ]
+    );
+
+    let discarded: Vec<_> = records()
+        .into_iter()
+        .try_chunks(2)
+        .discard_partial()
+        .collect();
+    assert_eq!(
+        discarded,
+        vec![
+            Ok(vec![1, 2]),
+            Err("a"),
+            Err("b"),
+            Ok(vec![4, 5]),
+            Ok(vec![6, 7]),
+        ]
+    );
+
+    let attached: Vec<_> = records()
+        .into_iter()
+        .try_chunks(3)
+        .attach_partial()
+        .map(|r| r.map_err(|e| e.into_parts()))
+        .collect();
+    assert_eq!(
+        attached,
+        vec![
+            Ok(vec![1, 2, 3]),
+            Err((vec![], "a")),
+            Err((vec![], "b")),
+            Ok(vec![4, 5, 6]),
+            Ok(vec![7]),
+        ]
+    );
+}
+
+#[test]
+fn test_try_chunks_size_hint() {
+    let chunks = records().into_iter().try_chunks(2);
+    let (lo, hi) = chunks.size_hint();
+    let count = chunks.count();
+    assert!(lo <= count && count <= hi.unwrap());
+
+    let mut chunks = vec![Ok::<_, ()>(1)].into_iter().try_chunks(4);
+    assert_eq!(chunks.size_hint(), (1, Some(1)));
+    assert_eq!(chunks.next(), Some(Ok(vec![1])));
+    assert_eq!(chunks.size_hint(), (0, Some(0)));
+    assert_eq!(chunks.next(), None);
+}
+
+#[test]
+fn test_try_array_chunks_attach_partial() {
+    let mut chunks = records()
+        .into_iter()
+        .try_array_chunks::<2>()
+        .attach_partial();
+    assert_eq!(chunks.next(), Some(Ok([1, 2])));
+    let err = chunks.next().unwrap().unwrap_err();
+    assert_eq!(err.partial(), &[3]);
+    assert_eq!(err.to_string(), "chunk interrupted after 1 elements: a");
+    assert_eq!(
+        chunks.next().unwrap().unwrap_err().into_parts(),
+        (vec![], "b")
+    );
+    assert_eq!(chunks.next(), Some(Ok([4, 5])));
+    assert_eq!(chunks.next(), Some(Ok([6, 7])));
+    assert_eq!(chunks.next(), None);
+    assert!(chunks.remainder().is_empty());
+}
+
+#[test]
+#[should_panic(expected = "chunk size must be non-zero")]
+fn test_try_chunks_zero() {
+    let _ = records().into_iter().try_chunks(0);
+}
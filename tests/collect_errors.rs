@@ -0,0 +1,84 @@
+use std::collections::{BTreeMap, HashSet};
+
+use tryiter::{ErrorCollector, IndexedError, TryIteratorExt};
+
+/// A container which always holds at least one error.
+#[derive(Debug, PartialEq)]
+struct NonEmpty<T> {
+    head: T,
+    tail: Vec<T>,
+}
+
+impl<T> ErrorCollector<T> for NonEmpty<T> {
+    fn from_error(error: T) -> Self {
+        NonEmpty {
+            head: error,
+            tail: vec![],
+        }
+    }
+
+    fn push_error(&mut self, error: T) {
+        self.tail.push(error);
+    }
+}
+
+/// Collects only the positions of failing records.
+#[derive(Default, Debug, PartialEq)]
+struct Positions(Vec<usize>);
+
+impl<E> Extend<IndexedError<E>> for Positions {
+    fn extend<I: IntoIterator<Item = IndexedError<E>>>(&mut self, iter: I) {
+        self.0.extend(iter.into_iter().map(|err| err.index()));
+    }
+}
+
+#[test]
+fn test_collect_all_errors_containers() {
+    let records = vec![Ok(("a", 1)), Err("x"), Ok(("b", 2)), Err("y"), Err("z")];
+
+    let errors = records
+        .clone()
+        .into_iter()
+        .try_collect_all_errors::<BTreeMap<_, _>, NonEmpty<_>>()
+        .unwrap_err();
+    assert_eq!(errors.head, IndexedError::new(1, "x"));
+    assert_eq!(
+        errors
+            .tail
+            .into_iter()
+            .map(IndexedError::into_error)
+            .collect::<Vec<_>>(),
+        vec!["y", "z"]
+    );
+
+    let positions = records
+        .clone()
+        .into_iter()
+        .try_collect_all_errors::<Vec<_>, Positions>()
+        .unwrap_err();
+    assert_eq!(positions, Positions(vec![1, 3, 4]));
+
+    let unique = records
+        .into_iter()
+        .try_collect_all_errors::<Vec<_>, HashSet<_>>()
+        .unwrap_err();
+    assert_eq!(unique.len(), 3);
+
+    let valid = vec![Ok::<_, &str>(("a", 1)), Ok(("b", 2))].into_iter();
+    let map = valid
+        .try_collect_all_errors::<BTreeMap<_, _>, NonEmpty<_>>()
+        .unwrap();
+    assert_eq!(map.get("b"), Some(&2));
+}
+
+#[test]
+fn test_collect_all_errors_short_collection() {
+    // `Option` stops collecting at the first `None`, the remaining errors
+    // must still be gathered.
+    let vals = vec![Ok(Some(1)), Ok(None), Ok(Some(2)), Err(5)];
+    let errors = vals
+        .into_iter()
+        .try_collect_all_errors::<Option<Vec<_>>, Vec<_>>()
+        .unwrap_err();
+    assert_eq!(errors, vec![IndexedError::new(3, 5)]);
+}
@@ -0,0 +1,73 @@
+use tryiter::TryIteratorExt;
+
+#[test]
+fn test_adapters_reverse() {
+    let vals: Vec<Result<i32, &str>> = vec![Ok(1), Err("error"), Ok(2), Ok(3), Ok(4)];
+
+    let rev: Vec<_> = vals
+        .clone()
+        .into_iter()
+        .map_ok(|x| Ok(x * 2))
+        .try_filter(|x| Ok(*x != 4))
+        .rev()
+        .collect();
+    assert_eq!(rev, vec![Ok(8), Ok(6), Err("error"), Ok(2)]);
+
+    let rev: Vec<_> = vals
+        .clone()
+        .into_iter()
+        .map_err(|e| e.len())
+        .try_filter_map(|x| Ok(if x % 2 == 0 { Some(x / 2) } else { None }))
+        .rev()
+        .collect();
+    assert_eq!(rev, vec![Ok(2), Ok(1), Err(5)]);
+
+    let mut seen = vec![];
+    let mut iter = vals
+        .into_iter()
+        .err_into::<String>()
+        .inspect_ok(|x| seen.push(*x))
+        .inspect_err(|_| {});
+    assert_eq!(iter.try_next_back(), Ok(Some(4)));
+    assert_eq!(iter.try_next(), Ok(Some(1)));
+    assert_eq!(iter.try_rfind(|x| Ok(*x < 3)), Ok(Some(2)));
+    assert_eq!(iter.try_next_back(), Err("error".to_string()));
+    assert_eq!(iter.try_next_back(), Ok(None));
+    drop(iter);
+    assert_eq!(seen, vec![4, 1, 3, 2]);
+}
+
+#[test]
+fn test_try_peekable_double_ended() {
+    let vals: Vec<Result<i32, &str>> = vec![Ok(1), Ok(2), Ok(3)];
+
+    let mut peek = vals.clone().into_iter().try_peekable();
+    assert_eq!(peek.try_peek(), Ok(Some(&1)));
+    assert_eq!(peek.try_peek_back(), Ok(Some(&3)));
+    assert_eq!(peek.size_hint(), (3, Some(3)));
+    assert_eq!(peek.clone().count(), 3);
+    assert_eq!(peek.clone().last(), Some(Ok(3)));
+    assert_eq!(peek.clone().nth(1), Some(Ok(2)));
+    assert_eq!(
+        peek.clone().rev().collect::<Vec<_>>(),
+        vec![Ok(3), Ok(2), Ok(1)]
+    );
+    assert_eq!(peek.clone().collect::<Vec<_>>(), vec![Ok(1), Ok(2), Ok(3)]);
+
+    assert_eq!(peek.next(), Some(Ok(1)));
+    assert_eq!(peek.next(), Some(Ok(2)));
+
+    // the only remaining value is the one peeked from the back
+    assert_eq!(peek.try_peek(), Ok(Some(&3)));
+    assert_eq!(peek.try_peek_back(), Ok(Some(&3)));
+    assert_eq!(peek.next_back(), Some(Ok(3)));
+    assert_eq!(peek.try_peek_back(), Ok(None));
+    assert_eq!(peek.next(), None);
+
+    // a value peeked from the back is yielded once the front catches up
+    let mut peek = vals.into_iter().try_peekable();
+    if let Ok(Some(v)) = peek.try_peek_back_mut() {
+        *v = 30;
+    }
+    assert_eq!(peek.collect::<Vec<_>>(), vec![Ok(1), Ok(2), Ok(30)]);
+}
@@ -0,0 +1,162 @@
+#![cfg(feature = "external-sort")]
+
+use std::cell::Cell;
+use std::fmt;
+use std::io::{self, BufRead, Write};
+
+use tryiter::{ExternalSort, ExternalSortError, SortCodec, TryIteratorExt};
+
+/// Encodes `(key, seq)` pairs as lines, failing to encode `POISON` keys.
+#[derive(Debug)]
+struct Pairs;
+
+const POISON: u32 = u32::MAX;
+
+#[derive(Debug, PartialEq)]
+struct CodecError(String);
+
+impl fmt::Display for CodecError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+impl From<io::Error> for CodecError {
+    fn from(e: io::Error) -> Self {
+        CodecError(e.to_string())
+    }
+}
+
+impl SortCodec<(u32, u32)> for Pairs {
+    type Error = CodecError;
+
+    fn encode(
+        &mut self,
+        &(key, seq): &(u32, u32),
+        writer: &mut dyn Write,
+    ) -> Result<(), CodecError> {
+        if key == POISON {
+            return Err(CodecError("poisoned".to_string()));
+        }
+        writeln!(writer, "{key} {seq}")?;
+        Ok(())
+    }
+
+    fn decode(&mut self, reader: &mut dyn BufRead) -> Result<Option<(u32, u32)>, CodecError> {
+        let mut line = String::new();
+        if reader.read_line(&mut line)? == 0 {
+            return Ok(None);
+        }
+        let (key, seq) = line.trim_end().split_once(' ').unwrap();
+        Ok(Some((key.parse().unwrap(), seq.parse().unwrap())))
+    }
+}
+
+fn scrambled(len: u32) -> impl Iterator<Item = Result<(u32, u32), &'static str>> {
+    (0..len).map(|i| Ok(((i * 7919) % 101, i)))
+}
+
+#[test]
+fn test_external_sort_is_stable() {
+    for (len, run_len) in [
+        (0, 10),
+        (9, 10),
+        (10, 10),
+        (1000, 7),
+        (1000, 1),
+        (1000, 2000),
+    ] {
+        let mut expected: Vec<_> = scrambled(len).map(Result::unwrap).collect();
+        expected.sort_by_key(|x| x.0);
+
+        let config = ExternalSort::new(Pairs).run_len(run_len);
+        let sorted = scrambled(len)
+            .try_external_sorted_by(config, |a, b| Ok(a.0.cmp(&b.0)))
+            .unwrap();
+        let sorted: Vec<_> = sorted.map(|x| x.unwrap()).collect();
+        assert_eq!(sorted, expected, "len {len}, run_len {run_len}");
+    }
+}
+
+#[test]
+fn test_external_sort_temp_dir() {
+    let dir = std::env::temp_dir();
+    let config = ExternalSort::new(Pairs).run_len(3).temp_dir(&dir);
+    let sorted = scrambled(20)
+        .try_external_sorted_by(config, |a, b| Ok(a.cmp(b)))
+        .unwrap();
+    assert_eq!(sorted.count(), 20);
+}
+
+#[test]
+fn test_external_sort_merges_in_passes_above_fan_in() {
+    let dir = std::env::temp_dir().join(format!("tryiter-fan-in-{}", std::process::id()));
+    std::fs::create_dir_all(&dir).unwrap();
+    let files = || std::fs::read_dir(&dir).unwrap().count();
+
+    let mut expected: Vec<_> = scrambled(1000).map(Result::unwrap).collect();
+    expected.sort_by_key(|x| x.0);
+
+    // 142 spilled runs and one in memory, merged 3 at a time
+    let config = ExternalSort::new(Pairs).run_len(7).fan_in(3).temp_dir(&dir);
+    let sorted = scrambled(1000)
+        .try_external_sorted_by(config, |a, b| Ok(a.0.cmp(&b.0)))
+        .unwrap();
+    assert!(files() <= 3, "{} files left to merge", files());
+
+    let sorted: Vec<_> = sorted.map(|x| x.unwrap()).collect();
+    assert_eq!(sorted, expected);
+    assert_eq!(files(), 0);
+    std::fs::remove_dir(&dir).unwrap();
+}
+
+#[test]
+fn test_external_sort_source_and_codec_errors() {
+    let input = scrambled(100).chain([Err("truncated")]);
+    let config = ExternalSort::new(Pairs).run_len(10);
+    let err = input
+        .try_external_sorted_by(config, |a, b| Ok(a.cmp(b)))
+        .unwrap_err();
+    assert!(matches!(err, ExternalSortError::Source("truncated")));
+
+    let input = scrambled(100)
+        .chain([Ok((POISON, 0))])
+        .chain(scrambled(100));
+    let config = ExternalSort::new(Pairs).run_len(10);
+    let err = input
+        .try_external_sorted_by(config, |a, b| Ok(a.cmp(b)))
+        .unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "failed to encode or decode a sorted run: poisoned"
+    );
+}
+
+#[test]
+fn test_external_sort_comparator_error_while_merging() {
+    let calls = Cell::new(0);
+    let limit = Cell::new(usize::MAX);
+    let mut sorted = scrambled(100)
+        .try_external_sorted_by(ExternalSort::new(Pairs).run_len(25), |a, b| {
+            calls.set(calls.get() + 1);
+            if calls.get() > limit.get() {
+                Err("comparator")
+            } else {
+                Ok(a.cmp(b))
+            }
+        })
+        .unwrap();
+    // fail a few comparisons into the merge
+    limit.set(calls.get() + 5);
+
+    let mut yielded = 0;
+    let err = loop {
+        match sorted.next().unwrap() {
+            Ok(_) => yielded += 1,
+            Err(e) => break e,
+        }
+    };
+    assert!(matches!(err, ExternalSortError::Source("comparator")));
+    assert!(yielded > 0 && yielded < 100);
+    assert!(sorted.next().is_none());
+}
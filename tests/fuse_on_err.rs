@@ -0,0 +1,90 @@
+use std::cell::Cell;
+use std::iter::FusedIterator;
+
+use tryiter::TryIteratorExt;
+
+fn assert_fused<I: FusedIterator>(_: &I) {}
+
+#[test]
+fn test_fuse_on_err_never_polls_again() {
+    let polls = Cell::new(0);
+    let cursor = std::iter::from_fn(|| {
+        polls.set(polls.get() + 1);
+        Some(if polls.get() == 2 {
+            Err("closed")
+        } else {
+            Ok(polls.get())
+        })
+    });
+
+    let mut iter = cursor.fuse_on_err();
+    assert_fused(&iter);
+    assert_eq!(iter.next(), Some(Ok(1)));
+    assert!(!iter.is_terminated());
+    assert_eq!(iter.next(), Some(Err("closed")));
+    assert!(iter.is_terminated());
+    assert_eq!(iter.size_hint(), (0, Some(0)));
+    assert_eq!(iter.next(), None);
+    assert_eq!(iter.next(), None);
+    assert_eq!(polls.get(), 2);
+
+    // the error was yielded, not kept
+    assert_eq!(iter.take_error(), None);
+}
+
+#[test]
+fn test_oks_stores_error() {
+    let mut iter = vec![Ok(1), Ok(2), Err("a"), Err("b"), Ok(3)]
+        .into_iter()
+        .fuse_on_err();
+
+    let oks: Vec<_> = iter.oks().collect();
+    assert_eq!(oks, vec![1, 2]);
+    assert_eq!(iter.oks().next(), None);
+    assert_eq!(iter.take_error(), Some("a"));
+    assert_eq!(iter.take_error(), None);
+
+    // without error, the whole iterator is consumed
+    let mut iter = vec![Ok::<_, ()>(1), Ok(2)].into_iter().fuse_on_err();
+    assert_eq!(iter.oks().sum::<i32>(), 3);
+    assert_eq!(iter.take_error(), None);
+}
+
+#[test]
+fn test_fuse_on_err_from_back() {
+    let mut iter = vec![Ok(1), Err("a"), Ok(2)].into_iter().fuse_on_err();
+    assert_eq!(iter.next_back(), Some(Ok(2)));
+    assert_eq!(iter.next_back(), Some(Err("a")));
+    assert_eq!(iter.next(), None);
+}
+
+#[test]
+fn test_fuse_on_err_chains_with_adapters() {
+    let polls = Cell::new(0);
+    let cursor = std::iter::from_fn(|| {
+        polls.set(polls.get() + 1);
+        Some(if polls.get() == 3 {
+            Err("closed")
+        } else {
+            Ok(polls.get())
+        })
+    });
+
+    let results: Vec<_> = cursor
+        .fuse_on_err()
+        .map_ok(|x| Ok(x * 10))
+        .try_filter(|&x| Ok(x > 10))
+        .collect();
+    assert_eq!(results, vec![Ok(20), Err("closed")]);
+    assert_eq!(polls.get(), 3);
+}
+
+#[test]
+fn test_oks_from_back() {
+    let mut iter = vec![Ok(1), Err("a"), Ok(2)].into_iter().fuse_on_err();
+    assert_eq!(iter.oks().next_back(), Some(2));
+    assert_eq!(iter.oks().next_back(), None);
+    assert!(iter.is_terminated());
+    assert_eq!(iter.next(), None);
+    assert_eq!(iter.take_error(), Some("a"));
+}
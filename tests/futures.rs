@@ -0,0 +1,80 @@
+#![cfg(feature = "futures")]
+
+use futures::executor::block_on;
+use futures::stream::{self, StreamExt, TryStreamExt};
+use tryiter::{block_on_try_stream, TryIteratorExt};
+
+#[test]
+fn test_into_try_stream() {
+    let v = vec![Ok(1), Ok(2), Err("error"), Ok(3)];
+    let results: Vec<_> = block_on(
+        v.clone()
+            .into_iter()
+            .map_ok(|x| Ok(x * 10))
+            .into_try_stream()
+            .collect(),
+    );
+    assert_eq!(results, vec![Ok(10), Ok(20), Err("error"), Ok(30)]);
+
+    // TryStreamExt short-circuits on the first error
+    let collected: Result<Vec<_>, _> = block_on(v.into_iter().into_try_stream().try_collect());
+    assert_eq!(collected, Err("error"));
+}
+
+#[test]
+fn test_block_on_try_stream() {
+    let stream = stream::iter(1..=4)
+        .then(|x| async move {
+            if x == 3 {
+                Err(format!("failed on {}", x))
+            } else {
+                Ok(x)
+            }
+        })
+        .boxed();
+    let results: Vec<_> = block_on_try_stream(stream).map_ok(|x| Ok(x * 2)).collect();
+    assert_eq!(
+        results,
+        vec![Ok(2), Ok(4), Err("failed on 3".to_string()), Ok(8)]
+    );
+}
+
+#[test]
+fn test_round_trip() {
+    // the same adapter pipeline applied on both sides of the bridge
+    let pipeline = |iter: Box<dyn Iterator<Item = Result<i32, &'static str>>>| {
+        iter.try_filter(|x| Ok(x % 2 == 0)).map_ok(|x| Ok(x + 1))
+    };
+
+    let sync: Vec<_> =
+        pipeline(Box::new(vec![Ok(1), Ok(2), Err("e"), Ok(4)].into_iter())).collect();
+
+    let stream = vec![Ok(1), Ok(2), Err("e"), Ok(4)]
+        .into_iter()
+        .into_try_stream();
+    let bridged: Vec<_> = pipeline(Box::new(block_on_try_stream(stream))).collect();
+
+    assert_eq!(bridged, sync);
+    assert_eq!(sync, vec![Ok(3), Err("e"), Ok(5)]);
+}
+
+#[test]
+fn test_block_on_try_stream_is_fused() {
+    use std::task::Poll;
+
+    // a stream which resumes after ending
+    let mut polls = 0;
+    let stream = stream::poll_fn(move |_| {
+        polls += 1;
+        Poll::Ready(match polls {
+            1 => Some(Ok::<_, ()>(1)),
+            2 => None,
+            _ => Some(Ok(polls)),
+        })
+    });
+    let mut iter = block_on_try_stream(stream);
+    assert_eq!(iter.next(), Some(Ok(1)));
+    assert_eq!(iter.next(), None);
+    assert_eq!(iter.next(), None);
+    assert_eq!(iter.size_hint(), (0, Some(0)));
+}
@@ -0,0 +1,65 @@
+use std::error::Error;
+use std::num::ParseIntError;
+
+use tryiter::{IndexTracker, IndexedError, TryIteratorExt};
+
+#[test]
+fn test_indexed_error_source() {
+    let records = vec!["1", "2", "x", "4"];
+    let errors: Vec<IndexedError<ParseIntError, &str>> = records
+        .into_iter()
+        .map(str::parse::<i32>)
+        .try_filter(|x| Ok(*x > 0))
+        .context_err(|| "parsing records")
+        .filter_map(Result::err)
+        .collect();
+
+    assert_eq!(errors.len(), 1);
+    let err = &errors[0];
+    assert_eq!(err.index(), 2);
+    assert_eq!(err.label(), Some(&"parsing records"));
+
+    let source = err.source().expect("source error");
+    assert_eq!(source.to_string(), "invalid digit found in string");
+    assert!(source.downcast_ref::<ParseIntError>().is_some());
+}
+
+#[test]
+fn test_index_tracker_traces_closure_errors_after_filtering() {
+    let records = vec![1, 2, 3, 4, 5, 6, 7, 8];
+
+    let tracker = IndexTracker::new();
+    let pipeline = tracker
+        .track(records.into_iter().map(Ok::<_, &str>))
+        // drops the odd records before the closure can fail
+        .try_filter(|x| Ok(x % 2 == 0))
+        .map_ok(|x| {
+            if x % 3 == 0 {
+                Err("multiple of 3")
+            } else {
+                Ok(x)
+            }
+        })
+        .try_filter(|&x| if x == 8 { Err("eight") } else { Ok(true) });
+
+    let errors: Vec<_> = tracker
+        .context_err(pipeline, || "validating")
+        .filter_map(Result::err)
+        .map(|e| (e.index(), e.into_error()))
+        .collect();
+    assert_eq!(errors, vec![(5, "multiple of 3"), (7, "eight")]);
+    assert_eq!(tracker.last_index(), Some(7));
+}
+
+#[test]
+fn test_with_index_context_counts_its_own_elements() {
+    // without a tracker, positions count the elements reaching the adapter
+    let errors: Vec<_> = vec![Ok(1), Ok(2), Err("a"), Ok(3), Err("b")]
+        .into_iter()
+        .try_filter(|x| Ok(x % 2 == 0))
+        .with_index_context()
+        .filter_map(Result::err)
+        .map(|e| e.index())
+        .collect();
+    assert_eq!(errors, vec![1, 2]);
+}
@@ -0,0 +1,46 @@
+#![cfg(feature = "alloc")]
+
+use std::rc::Rc;
+
+use tryiter::TryIteratorExt;
+
+#[test]
+fn test_partition_results() {
+    let vals = vec![Ok(1), Err("a"), Ok(2), Err("b"), Ok(3)];
+    let (oks, errs): (Vec<_>, Vec<_>) = vals.into_iter().partition_results();
+    assert_eq!(oks, vec![1, 2, 3]);
+    assert_eq!(errs, vec!["a", "b"]);
+}
+
+#[test]
+fn test_partition_results_lazy_dropped_half_is_not_buffered() {
+    let marker = Rc::new(());
+    let vals: Vec<Result<u32, Rc<()>>> = (0..100)
+        .map(|i| {
+            if i % 2 == 0 {
+                Ok(i)
+            } else {
+                Err(marker.clone())
+            }
+        })
+        .collect();
+
+    let (oks, errs) = vals.into_iter().partition_results_lazy();
+    drop(errs);
+    assert_eq!(oks.count(), 50);
+    // every error was dropped rather than buffered for `errs`
+    assert_eq!(Rc::strong_count(&marker), 1);
+}
+
+#[test]
+fn test_partition_results_lazy_drop_clears_buffer() {
+    let marker = Rc::new(());
+    let vals: Vec<Result<Rc<()>, &str>> = vec![Ok(marker.clone()), Ok(marker.clone()), Err("a")];
+
+    let (oks, mut errs) = vals.into_iter().partition_results_lazy();
+    assert_eq!(errs.next(), Some("a"));
+    assert_eq!(Rc::strong_count(&marker), 3);
+    drop(oks);
+    assert_eq!(Rc::strong_count(&marker), 1);
+    assert_eq!(errs.next(), None);
+}
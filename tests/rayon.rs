@@ -0,0 +1,142 @@
+#![cfg(feature = "rayon")]
+
+use rayon::prelude::*;
+use tryiter::{TryIteratorExt, TryParallelIteratorExt};
+
+fn input() -> Vec<Result<i32, String>> {
+    (0..1000).map(Ok).collect()
+}
+
+fn input_with_errors() -> Vec<Result<i32, String>> {
+    (0..1000)
+        .map(|i| {
+            if i % 250 == 7 {
+                Err(format!("bad {i}"))
+            } else {
+                Ok(i)
+            }
+        })
+        .collect()
+}
+
+#[test]
+fn test_map_ok_matches_sequential() {
+    for v in [input(), input_with_errors()] {
+        let seq: Vec<_> = v.clone().into_iter().map_ok(|x| Ok(x * 3)).collect();
+        let par: Vec<_> = v.into_par_iter().map_ok(|x| Ok(x * 3)).collect();
+        assert_eq!(par, seq);
+    }
+}
+
+#[test]
+fn test_try_filter_map_matches_sequential() {
+    let f = |x: i32| {
+        if x == 500 {
+            Err("five hundred".to_string())
+        } else {
+            Ok((x % 3 == 0).then_some(x / 3))
+        }
+    };
+    for v in [input(), input_with_errors()] {
+        let seq: Vec<_> = v.clone().into_iter().try_filter_map(f).collect();
+        let par: Vec<_> = v.into_par_iter().try_filter_map(f).collect();
+        assert_eq!(par, seq);
+    }
+}
+
+#[test]
+fn test_try_all_try_any_match_sequential() {
+    let v = input();
+    assert_eq!(
+        v.clone().into_par_iter().try_all(|x| Ok(x < 1000)),
+        v.clone().into_iter().try_all(|x| Ok(x < 1000)),
+    );
+    assert_eq!(
+        v.clone().into_par_iter().try_all(|x| Ok(x < 999)),
+        v.clone().into_iter().try_all(|x| Ok(x < 999)),
+    );
+    assert_eq!(
+        v.clone().into_par_iter().try_any(|x| Ok(x == 999)),
+        v.clone().into_iter().try_any(|x| Ok(x == 999)),
+    );
+    assert_eq!(
+        v.clone().into_par_iter().try_any(|x| Ok(x > 999)),
+        v.into_iter().try_any(|x| Ok(x > 999)),
+    );
+}
+
+#[test]
+fn test_errors_win() {
+    // any error may win, but an error always wins over a successful result
+    let err = input_with_errors()
+        .into_par_iter()
+        .try_all(|_| Ok(true))
+        .unwrap_err();
+    assert!(err.starts_with("bad "));
+
+    let err = input_with_errors()
+        .into_par_iter()
+        .try_any(|_| Ok(false))
+        .unwrap_err();
+    assert!(err.starts_with("bad "));
+
+    let err = input()
+        .into_par_iter()
+        .try_all(|x| {
+            if x == 600 {
+                Err("closure".to_string())
+            } else {
+                Ok(true)
+            }
+        })
+        .unwrap_err();
+    assert_eq!(err, "closure");
+
+    let err = input_with_errors()
+        .into_par_iter()
+        .try_max_by_key(|x| *x)
+        .unwrap_err();
+    assert!(err.starts_with("bad "));
+
+    let err = input_with_errors()
+        .into_par_iter()
+        .map_ok(|x| Ok((x, x)))
+        .try_unzip::<_, _, Vec<_>, Vec<_>>()
+        .unwrap_err();
+    assert!(err.starts_with("bad "));
+}
+
+#[test]
+fn test_try_max_by_key_matches_sequential() {
+    // several elements share the maximum key, the last one must win
+    let v: Vec<Result<(i32, i32), String>> = (0..1000).map(|i| Ok((i % 10, i))).collect();
+    assert_eq!(
+        v.clone().into_par_iter().try_max_by_key(|(k, _)| *k),
+        v.into_iter().try_max_by_key(|(k, _)| *k),
+    );
+
+    let empty: Vec<Result<i32, String>> = vec![];
+    assert_eq!(empty.into_par_iter().try_max_by_key(|x| *x), Ok(None));
+}
+
+#[test]
+fn test_try_unzip_matches_sequential() {
+    let v: Vec<Result<(i32, i32), String>> = (0..1000).map(|i| Ok((i, -i))).collect();
+    let seq: Result<(Vec<_>, Vec<_>), _> = v.clone().into_iter().try_unzip();
+    let par: Result<(Vec<_>, Vec<_>), _> = v.into_par_iter().try_unzip();
+    assert_eq!(par, seq);
+}
+
+#[test]
+fn test_named_adapters_keep_indexing() {
+    use rayon::iter::IndexedParallelIterator;
+    use tryiter::parallel::MapOk;
+
+    let doubled: MapOk<_, _> = (0..100)
+        .into_par_iter()
+        .map(Ok::<_, ()>)
+        .map_ok(|x| Ok(x * 2));
+    assert_eq!(doubled.len(), 100);
+    let v: Vec<_> = doubled.rev().take(2).collect();
+    assert_eq!(v, vec![Ok(198), Ok(196)]);
+}
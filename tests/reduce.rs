@@ -0,0 +1,210 @@
+use tryiter::{CheckedNum, MinMaxResult, ReduceError, TryIteratorExt};
+
+#[test]
+fn test_try_sum_and_product() {
+    let sum = vec![Ok::<u64, &str>(1), Ok(2), Ok(3)].into_iter().try_sum();
+    assert_eq!(sum, Ok(6));
+    let product = vec![Ok::<i32, &str>(-2), Ok(3)].into_iter().try_product();
+    assert_eq!(product, Ok(-6));
+    let overflow = vec![Ok::<u32, &str>(1 << 16), Ok(1 << 16)]
+        .into_iter()
+        .try_product();
+    assert_eq!(overflow, Err(ReduceError::Overflow));
+
+    // the short names are aliases of the checked reductions
+    let v = || vec![Ok::<i64, &str>(i64::MAX), Ok(-3), Ok(2)].into_iter();
+    assert_eq!(v().try_sum(), v().try_checked_sum());
+    assert_eq!(v().try_product(), v().try_checked_product());
+}
+
+#[test]
+fn test_checked_reductions_short_circuit() {
+    let mut polled = 0;
+    let iter = vec![Ok(i32::MAX), Ok(1), Err("error")]
+        .into_iter()
+        .inspect(|_| polled += 1);
+    assert_eq!(iter.try_checked_sum(), Err(ReduceError::Overflow));
+    assert_eq!(polled, 2);
+
+    let mut polled = 0;
+    let iter = vec![Ok(1u8), Err("error"), Ok(u8::MAX), Ok(1)]
+        .into_iter()
+        .inspect(|_| polled += 1);
+    assert_eq!(iter.try_checked_sum(), Err(ReduceError::Source("error")));
+    assert_eq!(polled, 2);
+}
+
+#[test]
+fn test_checked_reductions_of_empty_iterators() {
+    let empty = || Vec::<Result<u128, ()>>::new().into_iter();
+    assert_eq!(empty().try_checked_sum(), Ok(0));
+    assert_eq!(empty().try_checked_product(), Ok(1));
+}
+
+#[test]
+fn test_checked_product_signed_overflow() {
+    let iter = vec![Ok::<isize, ()>(isize::MIN), Ok(-1)].into_iter();
+    assert_eq!(iter.try_checked_product(), Err(ReduceError::Overflow));
+    assert_eq!(<i16 as CheckedNum>::checked_mul(-128, 256), Some(i16::MIN));
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn test_reduce_error_display() {
+    use std::error::Error;
+    use std::fmt;
+
+    #[derive(Debug)]
+    struct Truncated;
+
+    impl fmt::Display for Truncated {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            f.write_str("truncated input")
+        }
+    }
+
+    impl Error for Truncated {}
+
+    let err = ReduceError::Source(Truncated);
+    assert_eq!(err.to_string(), "truncated input");
+    assert!(err.source().is_some());
+    assert_eq!(
+        ReduceError::<Truncated>::Overflow.to_string(),
+        "arithmetic overflow"
+    );
+    assert!(ReduceError::<Truncated>::Overflow.source().is_none());
+}
+
+#[test]
+fn test_try_minmax_ties_match_min_and_max() {
+    let v = || {
+        vec![
+            Ok((2, 'a')),
+            Ok((1, 'b')),
+            Ok((3, 'c')),
+            Ok((1, 'd')),
+            Ok((3, 'e')),
+        ]
+        .into_iter()
+    };
+    let by_key: Result<_, ()> = v().try_minmax_by_key(|x| x.0);
+    assert_eq!(by_key, Ok(MinMaxResult::MinMax((1, 'b'), (3, 'e'))));
+    assert_eq!(v().try_min_by_key(|x| x.0), Ok(Some((1, 'b'))));
+    assert_eq!(v().try_max_by_key(|x| x.0), Ok(Some((3, 'e'))));
+
+    // all elements equal: the first is the minimum and the last the maximum
+    let equal: Result<_, ()> = vec![Ok((0, 'a')), Ok((0, 'b')), Ok((0, 'c'))]
+        .into_iter()
+        .try_minmax_by(|x, y| x.0.cmp(&y.0));
+    assert_eq!(equal, Ok(MinMaxResult::MinMax((0, 'a'), (0, 'c'))));
+}
+
+#[test]
+fn test_try_minmax_by_key_single_pass() {
+    let mut calls = 0;
+    let minmax = vec![Ok::<_, &str>(4), Ok(8), Ok(1), Ok(6)]
+        .into_iter()
+        .try_minmax_by_key(|&x| {
+            calls += 1;
+            x
+        });
+    assert_eq!(minmax, Ok(MinMaxResult::MinMax(1, 8)));
+    assert_eq!(calls, 4);
+}
+
+#[test]
+fn test_try_minmax_short_circuits() {
+    let empty: Result<MinMaxResult<i32>, &str> = Vec::new().into_iter().try_minmax();
+    assert_eq!(empty, Ok(MinMaxResult::NoElements));
+
+    let mut polled = 0;
+    let minmax = vec![Ok(1), Ok(2), Err("error"), Ok(3)]
+        .into_iter()
+        .inspect(|_| polled += 1)
+        .try_minmax();
+    assert_eq!(minmax, Err("error"));
+    assert_eq!(polled, 3);
+
+    let second: Result<MinMaxResult<i32>, _> = vec![Ok(1), Err("error")].into_iter().try_minmax();
+    assert_eq!(second, Err("error"));
+}
+
+#[test]
+fn test_fallible_comparators_tie_breaking() {
+    let v = || {
+        vec![
+            Ok::<_, ()>((1, 'a')),
+            Ok((3, 'b')),
+            Ok((1, 'c')),
+            Ok((3, 'd')),
+        ]
+        .into_iter()
+    };
+    let cmp = |x: &(i32, char), y: &(i32, char)| Ok(x.0.cmp(&y.0));
+    assert_eq!(v().try_max_by_fallible(cmp), Ok(Some((3, 'd'))));
+    assert_eq!(v().try_min_by_fallible(cmp), Ok(Some((1, 'a'))));
+    assert_eq!(v().try_max_by_key_fallible(|x| Ok(x.0)), Ok(Some((3, 'd'))));
+    assert_eq!(v().try_min_by_key_fallible(|x| Ok(x.0)), Ok(Some((1, 'a'))));
+    assert_eq!(
+        v().try_max_by_fallible(cmp),
+        v().try_max_by(|x, y| x.0.cmp(&y.0))
+    );
+}
+
+#[test]
+fn test_fallible_key_errors_short_circuit() {
+    let mut calls = 0;
+    let max = vec![Ok::<_, String>("1"), Ok("x"), Ok("2")]
+        .into_iter()
+        .try_max_by_key_fallible(|s| {
+            calls += 1;
+            s.parse::<i32>().map_err(|e| e.to_string())
+        });
+    assert!(max.is_err());
+    assert_eq!(calls, 2);
+
+    let min = vec![Ok("1"), Err("source"), Ok("2")]
+        .into_iter()
+        .try_min_by_key_fallible(|_| Ok(0));
+    assert_eq!(min, Err("source"));
+    let empty: Result<Option<i32>, ()> = Vec::new().into_iter().try_min_by_key_fallible(|x| Ok(*x));
+    assert_eq!(empty, Ok(None));
+}
+
+#[test]
+fn test_partial_comparisons() {
+    let v = vec![Ok(0.0), Err("error"), Ok(f32::NAN)];
+    assert_eq!(
+        v.into_iter().try_max_partial(),
+        Err(ReduceError::Source("error"))
+    );
+
+    let v: Vec<Result<f32, ()>> = vec![Ok(0.0), Ok(f32::NAN)];
+    assert_eq!(
+        v.into_iter().try_min_partial(),
+        Err(ReduceError::Incomparable)
+    );
+
+    // a single NaN is rejected as if it had been compared
+    let v: Vec<Result<f32, ()>> = vec![Ok(f32::NAN)];
+    assert_eq!(
+        v.clone().into_iter().try_min_partial(),
+        Err(ReduceError::Incomparable)
+    );
+    assert_eq!(
+        v.into_iter().try_max_partial(),
+        Err(ReduceError::Incomparable)
+    );
+
+    let v: Vec<Result<f64, ()>> = vec![Ok(-0.0), Ok(0.0), Ok(f64::INFINITY)];
+    assert_eq!(
+        v.clone().into_iter().try_max_partial(),
+        Ok(Some(f64::INFINITY))
+    );
+    assert!(v
+        .into_iter()
+        .try_min_partial()
+        .unwrap()
+        .unwrap()
+        .is_sign_negative());
+}
@@ -0,0 +1,55 @@
+use std::collections::{BTreeMap, HashSet};
+
+use tryiter::{ErrorSlot, TryIteratorExt};
+
+#[test]
+fn test_shunt_into_from_iterator() {
+    let ok: Result<HashSet<_>, &str> = vec![Ok(1), Ok(2), Ok(1)]
+        .into_iter()
+        .shunt(|iter| iter.collect());
+    assert_eq!(ok, Ok(HashSet::from([1, 2])));
+
+    let mut pulled = 0;
+    let err: Result<BTreeMap<_, _>, _> = vec![Ok(("a", 1)), Err("bad"), Ok(("b", 2))]
+        .into_iter()
+        .inspect_ok(|_| pulled += 1)
+        .shunt(|iter| iter.collect());
+    assert_eq!(err, Err("bad"));
+    // the element after the error is never pulled
+    assert_eq!(pulled, 1);
+}
+
+#[test]
+fn test_shunt_size_hint() {
+    let v: Vec<Result<i32, ()>> = vec![Ok(1), Ok(2)];
+    let hint = v.into_iter().shunt(|iter| iter.size_hint());
+    assert_eq!(hint, Ok((0, Some(2))));
+}
+
+#[test]
+fn test_error_slot_shared_across_sources() {
+    let mut slot = ErrorSlot::new();
+    let mut out = vec![];
+    out.extend(slot.shunt(vec![Ok(1), Ok(2)].into_iter()));
+    assert!(!slot.is_set());
+    out.extend(slot.shunt(vec![Ok(3), Err("first"), Ok(4)].into_iter()));
+    assert_eq!(slot.error(), Some(&"first"));
+
+    // a slot holding an error stops every further shunt
+    let mut rest = slot.shunt(vec![Ok(5), Err("second")].into_iter());
+    assert_eq!(rest.size_hint(), (0, Some(0)));
+    assert_eq!(rest.next(), None);
+
+    assert_eq!(out, vec![1, 2, 3]);
+    assert_eq!(slot.into_result(out), Err("first"));
+}
+
+#[test]
+fn test_shunt_from_back() {
+    let mut slot = ErrorSlot::new();
+    let mut iter = slot.shunt(vec![Ok(1), Err("a"), Ok(2)].into_iter());
+    assert_eq!(iter.next_back(), Some(2));
+    assert_eq!(iter.next_back(), None);
+    assert_eq!(iter.next(), None);
+    assert_eq!(slot.take(), Some("a"));
+}
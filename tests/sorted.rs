@@ -0,0 +1,93 @@
+#![cfg(feature = "alloc")]
+
+use std::cmp::Ordering;
+
+use tryiter::TryIteratorExt;
+
+fn scrambled(len: u32) -> impl Iterator<Item = Result<(u32, u32), &'static str>> + Clone {
+    (0..len).map(|i| Ok(((i * 7919) % 13, i)))
+}
+
+#[test]
+fn test_try_sorted_by_matches_stable_sort() {
+    for len in [0, 1, 15, 16, 17, 100, 1000] {
+        let mut expected: Vec<_> = scrambled(len).map(Result::unwrap).collect();
+        expected.sort_by_key(|x| x.0);
+
+        let sorted = scrambled(len).try_sorted_by(|a, b| Ok(a.0.cmp(&b.0)));
+        assert_eq!(sorted.unwrap().collect::<Vec<_>>(), expected);
+        let sorted = scrambled(len).try_sorted_by_key(|x| Ok(x.0));
+        assert_eq!(sorted.unwrap().collect::<Vec<_>>(), expected);
+    }
+}
+
+#[test]
+fn test_try_sorted_by_stops_at_comparator_error() {
+    let mut calls = 0;
+    let sorted = scrambled(1000).try_sorted_by(|a, b| {
+        calls += 1;
+        if calls == 50 {
+            Err("comparator")
+        } else {
+            Ok(a.cmp(b))
+        }
+    });
+    assert_eq!(sorted.unwrap_err(), "comparator");
+    assert_eq!(calls, 50);
+}
+
+#[test]
+fn test_try_sorted_by_inconsistent_comparator_does_not_panic() {
+    let mut flip = false;
+    let sorted = scrambled(500).try_sorted_by(|_, _| {
+        flip = !flip;
+        Ok(if flip {
+            Ordering::Less
+        } else {
+            Ordering::Greater
+        })
+    });
+    assert_eq!(sorted.unwrap().len(), 500);
+}
+
+#[test]
+fn test_try_sorted_stops_at_source_error() {
+    let mut polled = 0;
+    let iter = vec![Ok(3), Ok(1), Err("error"), Ok(2)]
+        .into_iter()
+        .inspect(|_| polled += 1);
+    assert_eq!(
+        iter.try_sorted_by(|a, b| Ok(a.cmp(b))).unwrap_err(),
+        "error"
+    );
+    assert_eq!(polled, 3);
+
+    let mut calls = 0;
+    let sorted = vec![Ok(2), Ok(1), Err("error")]
+        .into_iter()
+        .try_sorted_by_key(|&x| {
+            calls += 1;
+            Ok(x)
+        });
+    assert_eq!(sorted.unwrap_err(), "error");
+    assert_eq!(calls, 2);
+}
+
+#[test]
+fn test_try_sorted_by_key_stops_at_key_error() {
+    let mut calls = 0;
+    let mut polled = 0;
+    let sorted = scrambled(100)
+        .inspect(|_| polled += 1)
+        .try_sorted_by_key(|&(key, _)| {
+            calls += 1;
+            if calls == 10 {
+                Err("key")
+            } else {
+                Ok(key)
+            }
+        });
+    assert_eq!(sorted.unwrap_err(), "key");
+    assert_eq!(calls, 10);
+    assert_eq!(polled, 10);
+}
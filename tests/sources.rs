@@ -0,0 +1,133 @@
+use tryiter::sources::{try_from_fn, try_repeat_with, try_successors, try_unfold};
+use tryiter::TryIteratorExt;
+
+/// A reader which fails on the pages listed in `failures`, once each.
+struct PagedReader {
+    pages: Vec<Vec<u32>>,
+    failures: Vec<usize>,
+    next_page: usize,
+}
+
+impl PagedReader {
+    fn read_page(&mut self) -> Result<Option<Vec<u32>>, String> {
+        if let Some(pos) = self.failures.iter().position(|&p| p == self.next_page) {
+            self.failures.remove(pos);
+            return Err(format!("failed to read page {}", self.next_page));
+        }
+        let page = self.pages.get(self.next_page).cloned();
+        self.next_page += 1;
+        Ok(page)
+    }
+}
+
+fn reader() -> PagedReader {
+    PagedReader {
+        pages: vec![vec![1, 2], vec![3], vec![4, 5]],
+        failures: vec![1],
+        next_page: 0,
+    }
+}
+
+#[test]
+fn test_try_unfold_stops_on_first_error() {
+    let mut iter = try_unfold(reader(), PagedReader::read_page);
+    assert_eq!(iter.next(), Some(Ok(vec![1, 2])));
+    assert_eq!(iter.next(), Some(Err("failed to read page 1".to_string())));
+    assert_eq!(iter.next(), None);
+    assert_eq!(iter.next(), None);
+    assert_eq!(iter.state().next_page, 1);
+}
+
+#[test]
+fn test_try_unfold_continue_on_err() {
+    let values: Vec<_> = try_unfold(reader(), PagedReader::read_page)
+        .continue_on_err()
+        .try_flat_map(|page| Ok(page.into_iter().map(Ok)))
+        .collect();
+    assert_eq!(
+        values,
+        vec![
+            Ok(1),
+            Ok(2),
+            Err("failed to read page 1".to_string()),
+            Ok(3),
+            Ok(4),
+            Ok(5)
+        ]
+    );
+}
+
+#[test]
+fn test_try_from_fn() {
+    let mut reader = reader();
+    let pages: Vec<_> = try_from_fn(|| reader.read_page()).collect();
+    assert_eq!(
+        pages,
+        vec![Ok(vec![1, 2]), Err("failed to read page 1".to_string())]
+    );
+
+    // the iterator is fused once the closure returns Ok(None)
+    let mut calls = 0;
+    let mut iter = try_from_fn(|| {
+        calls += 1;
+        Ok::<Option<()>, ()>(None)
+    });
+    assert_eq!(iter.next(), None);
+    assert_eq!(iter.next(), None);
+    drop(iter);
+    assert_eq!(calls, 1);
+}
+
+#[test]
+fn test_try_successors() {
+    let collatz = |&n: &u64| match n {
+        1 => Ok(None),
+        0 => Err("zero"),
+        n if n % 2 == 0 => Ok(Some(n / 2)),
+        n => Ok(Some(3 * n + 1)),
+    };
+    let v: Result<Vec<_>, _> = try_successors(Some(6), collatz).collect();
+    assert_eq!(v, Ok(vec![6, 3, 10, 5, 16, 8, 4, 2, 1]));
+
+    let v: Vec<_> = try_successors(Some(0), collatz).collect();
+    assert_eq!(v, vec![Ok(0), Err("zero")]);
+
+    let v: Vec<_> = try_successors(None, collatz).collect();
+    assert_eq!(v, vec![]);
+}
+
+#[test]
+fn test_try_successors_continue_on_err() {
+    // fails once on every element before succeeding
+    let mut failed = false;
+    let v: Vec<_> = try_successors(Some(1), |&n| {
+        failed = !failed;
+        match (failed, n) {
+            (true, _) => Err(n),
+            (false, 3) => Ok(None),
+            (false, n) => Ok(Some(n + 1)),
+        }
+    })
+    .continue_on_err()
+    .collect();
+    assert_eq!(v, vec![Ok(1), Err(1), Ok(2), Err(2), Ok(3), Err(3)]);
+}
+
+#[test]
+fn test_try_repeat_with() {
+    let mut n = 0;
+    let mut iter = try_repeat_with(|| {
+        n += 1;
+        if n == 3 {
+            Err("three")
+        } else {
+            Ok(n)
+        }
+    });
+    assert_eq!(iter.size_hint(), (1, None));
+    assert_eq!(iter.next(), Some(Ok(1)));
+    assert_eq!(iter.next(), Some(Ok(2)));
+    assert_eq!(iter.next(), Some(Err("three")));
+    assert_eq!(iter.next(), None);
+    assert_eq!(iter.size_hint(), (0, Some(0)));
+}
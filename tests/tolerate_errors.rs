@@ -0,0 +1,76 @@
+#[cfg(feature = "alloc")]
+use tryiter::MaxErrorRate;
+use tryiter::TryIteratorExt;
+
+#[cfg(feature = "alloc")]
+#[test]
+fn test_max_error_rate() {
+    // at most 2 errors within any 4 consecutive elements
+    let v = [
+        Ok(0),
+        Err(1),
+        Err(2),
+        Ok(3),
+        Ok(4),
+        Err(5),
+        Ok(6),
+        Err(7),
+        Err(8),
+    ];
+    let mut dropped = vec![];
+    let results: Vec<_> = v
+        .into_iter()
+        .tolerate_errors(MaxErrorRate::new(2, 4), |err| dropped.push(*err))
+        .collect();
+
+    assert_eq!(dropped, vec![1, 2, 5, 7]);
+    assert_eq!(results.len(), 5);
+    let err = results.last().unwrap().as_ref().unwrap_err();
+    assert_eq!(*err.error(), 8);
+    assert_eq!(err.tolerated(), 4);
+    assert_eq!(err.position(), 8);
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn test_max_error_rate_unbounded() {
+    let v = (0..1000).map(|i| if i % 3 == 0 { Err(i) } else { Ok(i) });
+    let mut policy = v.tolerate_errors(MaxErrorRate::new(usize::MAX, usize::MAX), |_| {});
+    assert_eq!(policy.by_ref().count(), 666);
+    assert_eq!(policy.tolerated(), 334);
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn test_error_budget_exceeded_display() {
+    let err = [Err("bad"), Ok(1), Err("worse")]
+        .into_iter()
+        .tolerate_errors(tryiter::MaxErrors::new(1), |_| {})
+        .find_map(Result::err)
+        .unwrap();
+    assert_eq!(
+        err.to_string(),
+        "error budget exceeded at element 2 after tolerating 1 errors: worse"
+    );
+}
+
+#[test]
+fn test_tolerate_errors_composes() {
+    let v = [Ok(1), Err("a"), Ok(2), Err("b"), Ok(3)];
+    let mut seen = vec![];
+    let mut peek = v
+        .into_iter()
+        .inspect_err(|err| seen.push(*err))
+        .tolerate_errors(|err: &&str| *err == "a", |_| {})
+        .try_peekable();
+
+    assert_eq!(peek.try_peek().map_err(|e| e.into_error()), Ok(Some(&1)));
+    assert_eq!(peek.try_next().map_err(|e| e.into_error()), Ok(Some(1)));
+    // the tolerated error is skipped while peeking
+    assert_eq!(peek.try_peek().map_err(|e| e.into_error()), Ok(Some(&2)));
+    assert_eq!(peek.try_next().map_err(|e| e.into_error()), Ok(Some(2)));
+    assert_eq!(peek.try_peek().map_err(|e| e.into_error()), Err("b"));
+    assert_eq!(peek.try_next().map_err(|e| e.into_error()), Ok(None));
+    drop(peek);
+    assert_eq!(seen, vec!["a", "b"]);
+}
@@ -0,0 +1,52 @@
+use tryiter::TryIteratorExt;
+
+#[test]
+fn test_try_flatten_double_ended() {
+    let vals: Vec<Result<Vec<Result<i32, &str>>, &str>> = vec![
+        Ok(vec![Ok(1), Ok(2)]),
+        Err("outer"),
+        Ok(vec![]),
+        Ok(vec![Ok(3), Err("inner"), Ok(4)]),
+    ];
+
+    let rev: Vec<_> = vals.clone().into_iter().try_flatten().rev().collect();
+    assert_eq!(
+        rev,
+        vec![Ok(4), Err("inner"), Ok(3), Err("outer"), Ok(2), Ok(1)]
+    );
+
+    // consuming from both ends meets in the middle
+    let mut flat = vals.into_iter().try_flatten();
+    assert_eq!(flat.next(), Some(Ok(1)));
+    assert_eq!(flat.next_back(), Some(Ok(4)));
+    assert_eq!(flat.next_back(), Some(Err("inner")));
+    assert_eq!(flat.next(), Some(Ok(2)));
+    assert_eq!(flat.next(), Some(Err("outer")));
+    assert_eq!(flat.next(), Some(Ok(3)));
+    assert_eq!(flat.next(), None);
+    assert_eq!(flat.next_back(), None);
+}
+
+#[test]
+fn test_try_flatten_size_hint() {
+    let vals: Vec<Result<Vec<Result<i32, &str>>, &str>> =
+        vec![Ok(vec![Ok(1), Ok(2)]), Ok(vec![Ok(3)])];
+
+    let mut flat = vals.into_iter().try_flatten();
+    assert_eq!(flat.size_hint(), (0, None));
+    assert_eq!(flat.next(), Some(Ok(1)));
+    assert_eq!(flat.size_hint(), (1, None));
+    assert_eq!(flat.next_back(), Some(Ok(3)));
+    assert_eq!(flat.size_hint(), (1, Some(1)));
+}
+
+#[test]
+fn test_try_flat_map_clone() {
+    let vals: Vec<Result<usize, String>> = vec![Ok(2), Err("error".into()), Ok(1)];
+    let flat = vals.into_iter().try_flat_map(|n| Ok(vec![Ok(n); n]));
+
+    let first: Vec<_> = flat.clone().collect();
+    let rev: Vec<_> = flat.rev().collect();
+    assert_eq!(first, vec![Ok(2), Ok(2), Err("error".to_string()), Ok(1)]);
+    assert_eq!(rev, vec![Ok(1), Err("error".to_string()), Ok(2), Ok(2)]);
+}
@@ -0,0 +1,56 @@
+#![cfg(feature = "alloc")]
+
+use tryiter::TryIteratorExt;
+
+#[test]
+fn test_forward_errors_mid_lookahead() {
+    let vals: Vec<Result<i32, &str>> = vec![Ok(1), Err("a"), Ok(2), Err("b"), Ok(3)];
+    let mut iter = vals.into_iter().try_multipeek();
+
+    assert_eq!(iter.try_peek_nth(0), Ok(Some(&1)));
+    // each error on the way to the requested position is reported once
+    assert_eq!(iter.try_peek_nth(2), Err("a"));
+    assert_eq!(iter.try_peek_nth(2), Err("b"));
+    assert_eq!(iter.try_peek_nth(2), Ok(Some(&3)));
+    assert_eq!(iter.size_hint(), (3, Some(3)));
+
+    // the errors are gone from the sequence
+    assert_eq!(iter.collect::<Vec<_>>(), vec![Ok(1), Ok(2), Ok(3)]);
+}
+
+#[test]
+fn test_stash_errors_in_position() {
+    let vals: Vec<Result<i32, &str>> = vec![Ok(1), Err("a"), Ok(2), Err("b"), Ok(3)];
+    let mut iter = vals.clone().into_iter().try_multipeek().stash_errors();
+
+    assert_eq!(iter.try_peek_nth(4), Ok(Some(&3)));
+    assert_eq!(iter.try_peek_nth(3), Err("b"));
+    assert_eq!(iter.try_peek_nth(1), Err("a"));
+
+    assert_eq!(iter.try_peek(), Ok(Some(&1)));
+    assert_eq!(iter.try_peek(), Err("a"));
+    assert_eq!(iter.try_peek(), Ok(Some(&2)));
+    iter.reset_peek();
+    assert_eq!(iter.try_peek(), Ok(Some(&1)));
+
+    assert_eq!(iter.collect::<Vec<_>>(), vals);
+}
+
+#[test]
+fn test_peek_mut_and_cursor_reset() {
+    let mut iter = (1..=4).map(Ok::<_, ()>).try_multipeek();
+
+    *iter.try_peek_nth_mut(2).unwrap().unwrap() *= 10;
+    assert_eq!(iter.try_peek(), Ok(Some(&1)));
+    assert_eq!(iter.try_peek(), Ok(Some(&2)));
+
+    // consuming an element resets the cursor
+    assert_eq!(iter.next(), Some(Ok(1)));
+    assert_eq!(iter.try_peek(), Ok(Some(&2)));
+    assert_eq!(iter.try_peek(), Ok(Some(&30)));
+    assert_eq!(iter.try_peek(), Ok(Some(&4)));
+    assert_eq!(iter.try_peek(), Ok(None));
+    assert_eq!(iter.try_peek_nth(7), Ok(None));
+
+    assert_eq!(iter.collect::<Vec<_>>(), vec![Ok(2), Ok(30), Ok(4)]);
+}
@@ -0,0 +1,165 @@
+use tryiter::{TryIteratorExt, TryPeekable};
+
+#[derive(Debug, PartialEq)]
+enum Token {
+    Number(u32),
+    Plus,
+}
+
+type Chars = std::vec::IntoIter<Result<char, String>>;
+
+fn tokenize(mut chars: TryPeekable<Chars>) -> Result<Vec<Token>, String> {
+    let mut tokens = vec![];
+    while let Some(c) = chars.try_next()? {
+        match c {
+            '+' => tokens.push(Token::Plus),
+            ' ' => {}
+            c => {
+                let mut n = c.to_digit(10).ok_or(format!("unexpected {:?}", c))?;
+                while let Some(d) = chars.try_next_if(|c| {
+                    if c.is_alphabetic() {
+                        Err(format!("letter {:?} in number", c))
+                    } else {
+                        Ok(c.is_ascii_digit())
+                    }
+                })? {
+                    n = n * 10 + d.to_digit(10).unwrap();
+                }
+                tokens.push(Token::Number(n));
+            }
+        }
+    }
+    Ok(tokens)
+}
+
+fn chars(s: &str) -> TryPeekable<Chars> {
+    s.chars()
+        .map(|c| {
+            if c == '!' {
+                Err("read error".to_string())
+            } else {
+                Ok(c)
+            }
+        })
+        .collect::<Vec<_>>()
+        .into_iter()
+        .try_peekable()
+}
+
+#[test]
+fn test_try_next_if_tokenizer() {
+    assert_eq!(
+        tokenize(chars("12 + 3+45")),
+        Ok(vec![
+            Token::Number(12),
+            Token::Plus,
+            Token::Number(3),
+            Token::Plus,
+            Token::Number(45),
+        ])
+    );
+    assert_eq!(tokenize(chars("12!3")), Err("read error".to_string()));
+    assert_eq!(
+        tokenize(chars("12a")),
+        Err("letter 'a' in number".to_string())
+    );
+}
+
+#[test]
+fn test_try_next_if_keeps_value_on_predicate_error() {
+    let mut iter = vec![Ok::<_, &str>(1), Ok(2)].into_iter().try_peekable();
+    assert_eq!(iter.try_next_if(|_| Err("nope")), Err("nope"));
+    assert_eq!(iter.size_hint(), (2, Some(2)));
+    assert_eq!(iter.try_peek(), Ok(Some(&1)));
+    assert_eq!(iter.try_next_if_eq(&1), Ok(Some(1)));
+    assert_eq!(iter.try_next_if_eq(&2), Ok(Some(2)));
+    assert_eq!(iter.try_next_if_eq(&3), Ok(None));
+    assert_eq!(iter.next(), None);
+}
+
+#[test]
+fn test_try_next_if_with_peeked_back() {
+    let mut iter = vec![Ok::<_, &str>(1)].into_iter().try_peekable();
+    assert_eq!(iter.try_peek_back(), Ok(Some(&1)));
+    assert_eq!(iter.try_next_if_eq(&2), Ok(None));
+    assert_eq!(iter.try_next_if_eq(&1), Ok(Some(1)));
+    assert_eq!(iter.try_next_if_eq(&1), Ok(None));
+    assert_eq!(iter.next_back(), None);
+}
+
+#[test]
+fn test_stash_errors() {
+    let vals = vec![Ok(1), Err("a"), Ok(2), Err("b")];
+    let mut peek = vals.clone().into_iter().try_peekable().stash_errors();
+
+    assert_eq!(peek.try_peek(), Ok(Some(&1)));
+    assert_eq!(peek.next(), Some(Ok(1)));
+    assert_eq!(peek.try_peek(), Err("a"));
+    assert_eq!(peek.try_peek_mut(), Err("a"));
+    assert_eq!(peek.try_next_if(|_| Ok(true)), Err("a"));
+    assert_eq!(peek.size_hint(), (3, Some(3)));
+    assert_eq!(peek.clone().collect::<Vec<_>>(), vals[1..]);
+
+    // errors peeked from the back are retained as well
+    assert_eq!(peek.try_peek_back(), Err("b"));
+    assert_eq!(peek.try_peek_back(), Err("b"));
+    assert_eq!(
+        peek.clone().rev().collect::<Vec<_>>(),
+        vec![Err("b"), Ok(2), Err("a")]
+    );
+    assert_eq!(peek.clone().last(), Some(Err("b")));
+    assert_eq!(peek.clone().count(), 3);
+    assert_eq!(peek.clone().nth(1), Some(Ok(2)));
+
+    assert_eq!(peek.next(), Some(Err("a")));
+    assert_eq!(peek.try_peek(), Ok(Some(&2)));
+    assert_eq!(peek.next(), Some(Ok(2)));
+    assert_eq!(peek.try_peek(), Err("b"));
+    assert_eq!(peek.try_peek_back(), Err("b"));
+    assert_eq!(peek.next(), Some(Err("b")));
+    assert_eq!(peek.try_peek(), Ok(None));
+    assert_eq!(peek.next(), None);
+}
+
+#[test]
+fn test_forward_errors_is_default() {
+    let mut peek = vec![Err("a"), Ok(1)].into_iter().try_peekable();
+    assert_eq!(peek.try_peek(), Err("a"));
+    assert_eq!(peek.try_peek(), Ok(Some(&1)));
+    assert_eq!(peek.next(), Some(Ok(1)));
+}
+
+#[test]
+fn test_try_peeking_take_while() {
+    let mut iter = vec![Ok(1), Err("a"), Ok(2), Ok(3), Ok(4)]
+        .into_iter()
+        .try_peekable();
+
+    let mut run = iter.try_peeking_take_while(|&x| if x == 3 { Err("three") } else { Ok(true) });
+    assert_eq!(run.next(), Some(Ok(1)));
+    assert_eq!(run.next(), Some(Err("a")));
+    assert_eq!(run.next(), Some(Ok(2)));
+    assert_eq!(run.next(), Some(Err("three")));
+    assert_eq!(run.next(), None);
+
+    // the value the predicate failed on is still there
+    assert_eq!(iter.try_peek(), Ok(Some(&3)));
+    assert_eq!(iter.try_peeking_take_while(|_| Ok(true)).count(), 2);
+    assert_eq!(iter.next(), None);
+}
+
+#[test]
+fn test_try_peeking_take_while_stash_errors() {
+    let mut iter = vec![Ok(1), Err("a"), Ok(2)]
+        .into_iter()
+        .try_peekable()
+        .stash_errors();
+
+    let run: Vec<_> = iter.try_peeking_take_while(|_| Ok(true)).collect();
+    assert_eq!(run, vec![Ok(1), Err("a")]);
+
+    // the error is still pending
+    assert_eq!(iter.next(), Some(Err("a")));
+    let run: Vec<_> = iter.try_peeking_take_while(|_| Ok(true)).collect();
+    assert_eq!(run, vec![Ok(2)]);
+}
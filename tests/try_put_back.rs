@@ -0,0 +1,29 @@
+#![cfg(feature = "alloc")]
+
+use tryiter::TryIteratorExt;
+
+#[test]
+fn test_put_back_around_errors() {
+    let mut iter = vec![Ok(1), Err("a"), Ok(2)].into_iter().try_put_back();
+
+    assert_eq!(iter.try_next(), Ok(Some(1)));
+    iter.put_back(1);
+    iter.put_back_n(vec![10, 11]);
+    assert_eq!(iter.size_hint(), (5, Some(5)));
+
+    assert_eq!(iter.try_next(), Ok(Some(10)));
+    iter.put_back(9);
+    assert_eq!(
+        iter.clone().collect::<Vec<_>>(),
+        vec![Ok(9), Ok(11), Ok(1), Err("a"), Ok(2)]
+    );
+
+    assert_eq!(iter.try_next(), Ok(Some(9)));
+    assert_eq!(iter.try_next(), Ok(Some(11)));
+    assert_eq!(iter.try_next(), Ok(Some(1)));
+    assert_eq!(iter.try_next(), Err("a"));
+
+    // values put back after an error come before the remaining elements
+    iter.put_back(0);
+    assert_eq!(iter.collect::<Vec<_>>(), vec![Ok(0), Ok(2)]);
+}
@@ -0,0 +1,86 @@
+#![cfg(feature = "alloc")]
+
+use tryiter::TryIteratorExt;
+
+fn readings() -> Vec<Result<i32, &'static str>> {
+    vec![Ok(1), Ok(2), Ok(3), Err("a"), Ok(4), Err("b"), Ok(5), Ok(6)]
+}
+
+#[test]
+fn test_try_windows_pass_through() {
+    let windows: Vec<_> = readings().into_iter().try_windows(3).collect();
+    assert_eq!(
+        windows,
+        vec![
+            Ok(vec![1, 2, 3]),
+            Err("a"),
+            Ok(vec![2, 3, 4]),
+            Err("b"),
+            Ok(vec![3, 4, 5]),
+            Ok(vec![4, 5, 6]),
+        ]
+    );
+}
+
+#[test]
+fn test_try_windows_reset_on_err() {
+    let windows: Vec<_> = readings()
+        .into_iter()
+        .try_windows(2)
+        .reset_on_err()
+        .collect();
+    assert_eq!(
+        windows,
+        vec![
+            Ok(vec![1, 2]),
+            Ok(vec![2, 3]),
+            Err("a"),
+            Err("b"),
+            Ok(vec![5, 6]),
+        ]
+    );
+}
+
+#[test]
+fn test_try_tuple_windows() {
+    let triples: Vec<_> = readings()
+        .into_iter()
+        .try_tuple_windows::<(_, _, _)>()
+        .collect();
+    assert_eq!(
+        triples,
+        vec![
+            Ok((1, 2, 3)),
+            Err("a"),
+            Ok((2, 3, 4)),
+            Err("b"),
+            Ok((3, 4, 5)),
+            Ok((4, 5, 6)),
+        ]
+    );
+
+    let singles: Vec<_> = readings()
+        .into_iter()
+        .try_tuple_windows::<(_,)>()
+        .reset_on_err()
+        .collect();
+    assert_eq!(singles.len(), readings().len());
+
+    let mut sixes = readings()
+        .into_iter()
+        .try_tuple_windows::<(_, _, _, _, _, _)>();
+    assert_eq!(sixes.next(), Some(Err("a")));
+    assert_eq!(sixes.next(), Some(Err("b")));
+    assert_eq!(sixes.next(), Some(Ok((1, 2, 3, 4, 5, 6))));
+    assert_eq!(sixes.next(), None);
+}
+
+#[test]
+fn test_try_windows_size_hint() {
+    let iter = vec![Ok::<_, ()>(1), Ok(2), Ok(3), Ok(4)].into_iter();
+    let mut windows = iter.try_windows(3);
+    assert_eq!(windows.size_hint(), (2, Some(4)));
+    windows.next();
+    assert_eq!(windows.size_hint(), (1, Some(1)));
+    assert_eq!(windows.count(), 1);
+}